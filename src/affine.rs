@@ -0,0 +1,80 @@
+// everything Affine2 and Affine3 have in common; each type adds its own conversions to and from the full matrix
+macro_rules! affine_impl {
+    ($(#[$doc:meta])* $affine:ident,$mat:ident,$vec:ident,[$($c:ident),+],$f32:ident,$f64:ident) => {
+        $(#[$doc])*
+        #[derive(Copy,Clone,Debug)]
+        pub struct $affine<T> {
+            pub linear: $mat<T>,
+            pub translation: $vec<T>,
+        }
+
+        impl<T: Zero + One> $affine<T> {
+
+            /// identity transformation.
+            pub const IDENTITY: Self = $affine {
+                linear: $mat::IDENTITY,
+                translation: $vec { $($c: T::ZERO,)+ },
+            };
+        }
+
+        impl<T: Copy + Mul<Output=T> + Add<Output=T>> $affine<T> {
+
+            /// transform a point, including the translation.
+            pub fn transform_point(self,point: $vec<T>) -> $vec<T> {
+                self.linear * point + self.translation
+            }
+
+            /// transform a direction, without the translation.
+            pub fn transform_vector(self,vector: $vec<T>) -> $vec<T> {
+                self.linear * vector
+            }
+        }
+
+        impl<T: Copy + Zero + PartialEq + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>> $affine<T> {
+
+            /// inverse, or `None` if the linear part cannot be inverted.
+            ///
+            /// Only inverts the linear part, and moves the translation through it.
+            pub fn try_inverse(self) -> Option<Self> {
+                let linear = self.linear.try_inverse()?;
+                Some($affine {
+                    linear,
+                    translation: -(linear * self.translation),
+                })
+            }
+
+            /// inverse, or the transformation itself if it cannot be inverted.
+            pub fn inverse(self) -> Self {
+                self.try_inverse().unwrap_or(self)
+            }
+        }
+
+        impl<T> PartialEq for $affine<T> where $mat<T>: PartialEq,$vec<T>: PartialEq {
+            fn eq(&self,other: &Self) -> bool {
+                (self.linear == other.linear) && (self.translation == other.translation)
+            }
+        }
+
+        impl<T> Display for $affine<T> where $mat<T>: Display,$vec<T>: Display {
+            fn fmt(&self,f: &mut Formatter) -> Result {
+                write!(f,"[{},{}]",self.linear,self.translation)
+            }
+        }
+
+        // affine * affine
+        impl<T: Copy + Mul<Output=T> + Add<Output=T>> Mul<$affine<T>> for $affine<T> {
+            type Output = $affine<T>;
+            fn mul(self,other: $affine<T>) -> $affine<T> {
+                $affine {
+                    linear: self.linear * other.linear,
+                    translation: self.linear * other.translation + self.translation,
+                }
+            }
+        }
+
+        #[allow(non_camel_case_types)]
+        pub type $f32 = $affine<f32>;
+        #[allow(non_camel_case_types)]
+        pub type $f64 = $affine<f64>;
+    };
+}
@@ -0,0 +1,99 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Neg,
+        },
+    },
+};
+
+affine_impl! {
+    /// 2D affine transformation template.
+    ///
+    /// A [`Mat2x2`] followed by a [`Vec2`] translation, covering everything a [`Mat3x3`] does for 2D points except
+    /// projection. Typical for sprites, UI layout and 2D scene graphs.
+    ///
+    /// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+    Affine2,Mat2x2,Vec2,[x,y],f32a2,f64a2
+}
+
+impl<T: Copy + Zero + One> From<Affine2<T>> for Mat3x3<T> {
+    fn from(affine: Affine2<T>) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: affine.linear.x.x,y: affine.linear.x.y,z: T::ZERO, },
+            y: Vec3 { x: affine.linear.y.x,y: affine.linear.y.y,z: T::ZERO, },
+            z: Vec3 { x: affine.translation.x,y: affine.translation.y,z: T::ONE, },
+        }
+    }
+}
+
+impl<T: Copy> From<Mat3x3<T>> for Affine2<T> {
+    /// affine part of the matrix, ignoring the projective row.
+    fn from(matrix: Mat3x3<T>) -> Self {
+        Affine2 {
+            linear: Mat2x2 {
+                x: Vec2 { x: matrix.x.x,y: matrix.x.y, },
+                y: Vec2 { x: matrix.y.x,y: matrix.y.y, },
+            },
+            translation: Vec2 { x: matrix.z.x,y: matrix.z.y, },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn a() -> Affine2<f64> {
+        Affine2 {
+            linear: Mat2x2::from([2.0,0.5,-1.0,3.0]),
+            translation: Vec2 { x: 1.0,y: -2.0, },
+        }
+    }
+
+    fn b() -> Affine2<f64> {
+        Affine2 {
+            linear: Mat2x2::from([0.0,1.0,-2.0,0.0]),
+            translation: Vec2 { x: -0.5,y: 4.0, },
+        }
+    }
+
+    #[test]
+    fn inverse_matches_matrix() {
+        let a = a();
+        assert!(close(Mat3x3::from(a.inverse()),Mat3x3::from(a).inverse()));
+        assert!(close(Mat3x3::from(a * a.inverse()),Mat3x3::IDENTITY));
+    }
+
+    #[test]
+    fn composition_matches_matrix() {
+        let (a,b) = (a(),b());
+        assert!(close(Mat3x3::from(a * b),Mat3x3::from(a) * Mat3x3::from(b)));
+        let p = Vec2 { x: 0.25,y: -1.0, };
+        let q = Mat3x3::from(a) * p.to_homogeneous_point();
+        assert!(close(a.transform_point(p),q.truncate()));
+        assert!(close(a.transform_vector(p),(Mat3x3::from(a) * p.extend(0.0)).truncate()));
+        assert!(Affine2::from(Mat3x3::from(a)) == a);
+    }
+
+    #[test]
+    fn singular() {
+        let mut a = a();
+        a.linear.y = Vec2 { x: 4.0,y: 1.0, };
+        assert!(a.try_inverse().is_none());
+        assert!(a.inverse() == a);
+    }
+}
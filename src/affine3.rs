@@ -0,0 +1,100 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            Neg,
+        },
+    },
+};
+
+affine_impl! {
+    /// 3D affine transformation template.
+    ///
+    /// A [`Mat3x3`] followed by a [`Vec3`] translation, which is a [`Mat4x4`] without the projective row. Object and camera
+    /// transformations in a scene are of this form, and are cheaper to store, combine and invert this way.
+    ///
+    /// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+    Affine3,Mat3x3,Vec3,[x,y,z],f32a3,f64a3
+}
+
+impl<T: Copy + Zero + One> From<Affine3<T>> for Mat4x4<T> {
+    fn from(affine: Affine3<T>) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: affine.linear.x.x,y: affine.linear.x.y,z: affine.linear.x.z,w: T::ZERO, },
+            y: Vec4 { x: affine.linear.y.x,y: affine.linear.y.y,z: affine.linear.y.z,w: T::ZERO, },
+            z: Vec4 { x: affine.linear.z.x,y: affine.linear.z.y,z: affine.linear.z.z,w: T::ZERO, },
+            w: Vec4 { x: affine.translation.x,y: affine.translation.y,z: affine.translation.z,w: T::ONE, },
+        }
+    }
+}
+
+impl<T: Copy> From<Mat4x4<T>> for Affine3<T> {
+    /// affine part of the matrix, ignoring the projective row.
+    fn from(matrix: Mat4x4<T>) -> Self {
+        Affine3 {
+            linear: Mat3x3 {
+                x: Vec3 { x: matrix.x.x,y: matrix.x.y,z: matrix.x.z, },
+                y: Vec3 { x: matrix.y.x,y: matrix.y.y,z: matrix.y.z, },
+                z: Vec3 { x: matrix.z.x,y: matrix.z.y,z: matrix.z.z, },
+            },
+            translation: Vec3 { x: matrix.w.x,y: matrix.w.y,z: matrix.w.z, },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn a() -> Affine3<f64> {
+        Affine3 {
+            linear: Mat3x3::from([2.0,0.5,-1.0,1.0,3.0,0.0,-0.5,1.0,4.0]),
+            translation: Vec3 { x: 1.0,y: -2.0,z: 3.0, },
+        }
+    }
+
+    fn b() -> Affine3<f64> {
+        Affine3 {
+            linear: Mat3x3::from([0.0,1.0,0.0,-1.0,0.0,0.0,0.0,0.0,2.0]),
+            translation: Vec3 { x: -0.5,y: 4.0,z: 1.0, },
+        }
+    }
+
+    #[test]
+    fn inverse_matches_matrix() {
+        let a = a();
+        assert!(close(Mat4x4::from(a.inverse()),Mat4x4::from(a).inverse()));
+        assert!(close(Mat4x4::from(a * a.inverse()),Mat4x4::IDENTITY));
+    }
+
+    #[test]
+    fn composition_matches_matrix() {
+        let (a,b) = (a(),b());
+        assert!(close(Mat4x4::from(a * b),Mat4x4::from(a) * Mat4x4::from(b)));
+        let p = Vec3 { x: 0.25,y: -1.0,z: 2.0, };
+        let q = Mat4x4::from(a) * p.to_homogeneous_point();
+        assert!((a.transform_point(p) - q.truncate()).length() < 1e-12);
+        assert!(Affine3::from(Mat4x4::from(a)) == a);
+    }
+
+    #[test]
+    fn singular() {
+        let mut a = a();
+        a.linear.z = Vec3 { x: 0.0,y: 0.0,z: 0.0, };
+        assert!(a.try_inverse().is_none());
+        assert!(a.inverse() == a);
+    }
+}
@@ -0,0 +1,187 @@
+use {
+    crate::*,
+    std::{
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Not,
+            BitAnd,
+            BitOr,
+            BitXor,
+            BitAndAssign,
+            BitOrAssign,
+            BitXorAssign,
+        },
+    },
+};
+
+/// 2D boolean mask.
+///
+/// Result of component-wise comparisons on [`Vec2`] (`cmplt`, `cmpeq`, etc.). Masks can be combined with `&`, `|`, `^` and
+/// `!`, tested with [`BVec2::any`] and [`BVec2::all`], and used to pick components with [`Vec2::select`].
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl BVec2 {
+
+    /// `true` if any component is `true`.
+    pub fn any(self) -> bool {
+        self.x || self.y
+    }
+
+    /// `true` if all components are `true`.
+    pub fn all(self) -> bool {
+        self.x && self.y
+    }
+}
+
+/// Display the mask as `(x,y)`.
+impl Display for BVec2 {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{})",self.x,self.y)
+    }
+}
+
+// mask & mask
+impl BitAnd for BVec2 {
+    type Output = Self;
+    fn bitand(self,other: Self) -> Self::Output {
+        BVec2 { x: self.x & other.x,y: self.y & other.y, }
+    }
+}
+
+// mask &= mask
+impl BitAndAssign for BVec2 {
+    fn bitand_assign(&mut self,other: Self) {
+        self.x &= other.x;
+        self.y &= other.y;
+    }
+}
+
+// mask | mask
+impl BitOr for BVec2 {
+    type Output = Self;
+    fn bitor(self,other: Self) -> Self::Output {
+        BVec2 { x: self.x | other.x,y: self.y | other.y, }
+    }
+}
+
+// mask |= mask
+impl BitOrAssign for BVec2 {
+    fn bitor_assign(&mut self,other: Self) {
+        self.x |= other.x;
+        self.y |= other.y;
+    }
+}
+
+// mask ^ mask
+impl BitXor for BVec2 {
+    type Output = Self;
+    fn bitxor(self,other: Self) -> Self::Output {
+        BVec2 { x: self.x ^ other.x,y: self.y ^ other.y, }
+    }
+}
+
+// mask ^= mask
+impl BitXorAssign for BVec2 {
+    fn bitxor_assign(&mut self,other: Self) {
+        self.x ^= other.x;
+        self.y ^= other.y;
+    }
+}
+
+// !mask
+impl Not for BVec2 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        BVec2 { x: !self.x,y: !self.y, }
+    }
+}
+
+impl<T> Vec2<T> {
+
+    /// pick components from `a` where `mask` is `true`, and from `b` where it is `false`.
+    pub fn select(mask: BVec2,a: Self,b: Self) -> Self {
+        Vec2 {
+            x: if mask.x { a.x } else { b.x },
+            y: if mask.y { a.y } else { b.y },
+        }
+    }
+}
+
+impl<T: PartialOrd> Vec2<T> {
+
+    /// component-wise equal to.
+    pub fn cmpeq(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x == other.x,y: self.y == other.y, }
+    }
+
+    /// component-wise not equal to.
+    pub fn cmpne(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x != other.x,y: self.y != other.y, }
+    }
+
+    /// component-wise less than.
+    pub fn cmplt(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x < other.x,y: self.y < other.y, }
+    }
+
+    /// component-wise less than or equal to.
+    pub fn cmple(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x <= other.x,y: self.y <= other.y, }
+    }
+
+    /// component-wise greater than.
+    pub fn cmpgt(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x > other.x,y: self.y > other.y, }
+    }
+
+    /// component-wise greater than or equal to.
+    pub fn cmpge(self,other: Self) -> BVec2 {
+        BVec2 { x: self.x >= other.x,y: self.y >= other.y, }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks() {
+        let a = Vec2 { x: 1,y: 5, };
+        let b = Vec2 { x: 3,y: 5, };
+        assert_eq!(a.cmplt(b),BVec2 { x: true,y: false, });
+        assert_eq!(a.cmple(b),BVec2 { x: true,y: true, });
+        assert_eq!(a.cmpeq(b),BVec2 { x: false,y: true, });
+        assert_eq!(a.cmpne(b),BVec2 { x: true,y: false, });
+        assert_eq!(a.cmpgt(b),BVec2 { x: false,y: false, });
+        assert_eq!(a.cmpge(b),BVec2 { x: false,y: true, });
+        assert_eq!(a.cmplt(b) | a.cmpeq(b),a.cmple(b));
+        assert_eq!(a.cmple(b) & a.cmpge(b),a.cmpeq(b));
+        assert_eq!(a.cmplt(b) ^ a.cmple(b),a.cmpeq(b));
+        assert_eq!(!a.cmpeq(b),a.cmpne(b));
+        assert_eq!(Vec2::select(a.cmplt(b),a,b),Vec2 { x: 1,y: 5, });
+        assert_eq!(Vec2::select(a.cmpgt(b),a,b),Vec2 { x: 3,y: 5, });
+    }
+
+    #[test]
+    fn any_all() {
+        assert!(BVec2 { x: true,y: true, }.all());
+        assert!(!BVec2 { x: false,y: false, }.any());
+        let mixed = BVec2 { x: true,y: false, };
+        assert!(mixed.any() && !mixed.all());
+        let mut mask = mixed;
+        mask |= !mixed;
+        assert!(mask.all());
+        mask &= mixed;
+        assert_eq!(mask,mixed);
+        mask ^= mixed;
+        assert!(!mask.any());
+    }
+}
@@ -0,0 +1,192 @@
+use {
+    crate::*,
+    std::{
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Not,
+            BitAnd,
+            BitOr,
+            BitXor,
+            BitAndAssign,
+            BitOrAssign,
+            BitXorAssign,
+        },
+    },
+};
+
+/// 3D boolean mask.
+///
+/// Result of component-wise comparisons on [`Vec3`] (`cmplt`, `cmpeq`, etc.). Masks can be combined with `&`, `|`, `^` and
+/// `!`, tested with [`BVec3::any`] and [`BVec3::all`], and used to pick components with [`Vec3::select`].
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct BVec3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+impl BVec3 {
+
+    /// `true` if any component is `true`.
+    pub fn any(self) -> bool {
+        self.x || self.y || self.z
+    }
+
+    /// `true` if all components are `true`.
+    pub fn all(self) -> bool {
+        self.x && self.y && self.z
+    }
+}
+
+/// Display the mask as `(x,y,z)`.
+impl Display for BVec3 {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{},{})",self.x,self.y,self.z)
+    }
+}
+
+// mask & mask
+impl BitAnd for BVec3 {
+    type Output = Self;
+    fn bitand(self,other: Self) -> Self::Output {
+        BVec3 { x: self.x & other.x,y: self.y & other.y,z: self.z & other.z, }
+    }
+}
+
+// mask &= mask
+impl BitAndAssign for BVec3 {
+    fn bitand_assign(&mut self,other: Self) {
+        self.x &= other.x;
+        self.y &= other.y;
+        self.z &= other.z;
+    }
+}
+
+// mask | mask
+impl BitOr for BVec3 {
+    type Output = Self;
+    fn bitor(self,other: Self) -> Self::Output {
+        BVec3 { x: self.x | other.x,y: self.y | other.y,z: self.z | other.z, }
+    }
+}
+
+// mask |= mask
+impl BitOrAssign for BVec3 {
+    fn bitor_assign(&mut self,other: Self) {
+        self.x |= other.x;
+        self.y |= other.y;
+        self.z |= other.z;
+    }
+}
+
+// mask ^ mask
+impl BitXor for BVec3 {
+    type Output = Self;
+    fn bitxor(self,other: Self) -> Self::Output {
+        BVec3 { x: self.x ^ other.x,y: self.y ^ other.y,z: self.z ^ other.z, }
+    }
+}
+
+// mask ^= mask
+impl BitXorAssign for BVec3 {
+    fn bitxor_assign(&mut self,other: Self) {
+        self.x ^= other.x;
+        self.y ^= other.y;
+        self.z ^= other.z;
+    }
+}
+
+// !mask
+impl Not for BVec3 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        BVec3 { x: !self.x,y: !self.y,z: !self.z, }
+    }
+}
+
+impl<T> Vec3<T> {
+
+    /// pick components from `a` where `mask` is `true`, and from `b` where it is `false`.
+    pub fn select(mask: BVec3,a: Self,b: Self) -> Self {
+        Vec3 {
+            x: if mask.x { a.x } else { b.x },
+            y: if mask.y { a.y } else { b.y },
+            z: if mask.z { a.z } else { b.z },
+        }
+    }
+}
+
+impl<T: PartialOrd> Vec3<T> {
+
+    /// component-wise equal to.
+    pub fn cmpeq(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x == other.x,y: self.y == other.y,z: self.z == other.z, }
+    }
+
+    /// component-wise not equal to.
+    pub fn cmpne(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x != other.x,y: self.y != other.y,z: self.z != other.z, }
+    }
+
+    /// component-wise less than.
+    pub fn cmplt(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x < other.x,y: self.y < other.y,z: self.z < other.z, }
+    }
+
+    /// component-wise less than or equal to.
+    pub fn cmple(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x <= other.x,y: self.y <= other.y,z: self.z <= other.z, }
+    }
+
+    /// component-wise greater than.
+    pub fn cmpgt(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x > other.x,y: self.y > other.y,z: self.z > other.z, }
+    }
+
+    /// component-wise greater than or equal to.
+    pub fn cmpge(self,other: Self) -> BVec3 {
+        BVec3 { x: self.x >= other.x,y: self.y >= other.y,z: self.z >= other.z, }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks() {
+        let a = Vec3 { x: 1,y: 5,z: -2, };
+        let b = Vec3 { x: 3,y: 5,z: -4, };
+        assert_eq!(a.cmplt(b),BVec3 { x: true,y: false,z: false, });
+        assert_eq!(a.cmple(b),BVec3 { x: true,y: true,z: false, });
+        assert_eq!(a.cmpeq(b),BVec3 { x: false,y: true,z: false, });
+        assert_eq!(a.cmpne(b),BVec3 { x: true,y: false,z: true, });
+        assert_eq!(a.cmpgt(b),BVec3 { x: false,y: false,z: true, });
+        assert_eq!(a.cmpge(b),BVec3 { x: false,y: true,z: true, });
+        assert_eq!(a.cmplt(b) | a.cmpeq(b),a.cmple(b));
+        assert_eq!(a.cmple(b) & a.cmpge(b),a.cmpeq(b));
+        assert_eq!(a.cmplt(b) ^ a.cmple(b),a.cmpeq(b));
+        assert_eq!(!a.cmpeq(b),a.cmpne(b));
+        assert_eq!(Vec3::select(a.cmplt(b),a,b),Vec3 { x: 1,y: 5,z: -4, });
+        assert_eq!(Vec3::select(a.cmpgt(b),a,b),Vec3 { x: 3,y: 5,z: -2, });
+    }
+
+    #[test]
+    fn any_all() {
+        assert!(BVec3 { x: true,y: true,z: true, }.all());
+        assert!(!BVec3 { x: false,y: false,z: false, }.any());
+        let mixed = BVec3 { x: true,y: false,z: false, };
+        assert!(mixed.any() && !mixed.all());
+        let mut mask = mixed;
+        mask |= !mixed;
+        assert!(mask.all());
+        mask &= mixed;
+        assert_eq!(mask,mixed);
+        mask ^= mixed;
+        assert!(!mask.any());
+    }
+}
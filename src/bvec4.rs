@@ -0,0 +1,197 @@
+use {
+    crate::*,
+    std::{
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Not,
+            BitAnd,
+            BitOr,
+            BitXor,
+            BitAndAssign,
+            BitOrAssign,
+            BitXorAssign,
+        },
+    },
+};
+
+/// 4D boolean mask.
+///
+/// Result of component-wise comparisons on [`Vec4`] (`cmplt`, `cmpeq`, etc.). Masks can be combined with `&`, `|`, `^` and
+/// `!`, tested with [`BVec4::any`] and [`BVec4::all`], and used to pick components with [`Vec4::select`].
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct BVec4 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+    pub w: bool,
+}
+
+impl BVec4 {
+
+    /// `true` if any component is `true`.
+    pub fn any(self) -> bool {
+        self.x || self.y || self.z || self.w
+    }
+
+    /// `true` if all components are `true`.
+    pub fn all(self) -> bool {
+        self.x && self.y && self.z && self.w
+    }
+}
+
+/// Display the mask as `(x,y,z,w)`.
+impl Display for BVec4 {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{},{},{})",self.x,self.y,self.z,self.w)
+    }
+}
+
+// mask & mask
+impl BitAnd for BVec4 {
+    type Output = Self;
+    fn bitand(self,other: Self) -> Self::Output {
+        BVec4 { x: self.x & other.x,y: self.y & other.y,z: self.z & other.z,w: self.w & other.w, }
+    }
+}
+
+// mask &= mask
+impl BitAndAssign for BVec4 {
+    fn bitand_assign(&mut self,other: Self) {
+        self.x &= other.x;
+        self.y &= other.y;
+        self.z &= other.z;
+        self.w &= other.w;
+    }
+}
+
+// mask | mask
+impl BitOr for BVec4 {
+    type Output = Self;
+    fn bitor(self,other: Self) -> Self::Output {
+        BVec4 { x: self.x | other.x,y: self.y | other.y,z: self.z | other.z,w: self.w | other.w, }
+    }
+}
+
+// mask |= mask
+impl BitOrAssign for BVec4 {
+    fn bitor_assign(&mut self,other: Self) {
+        self.x |= other.x;
+        self.y |= other.y;
+        self.z |= other.z;
+        self.w |= other.w;
+    }
+}
+
+// mask ^ mask
+impl BitXor for BVec4 {
+    type Output = Self;
+    fn bitxor(self,other: Self) -> Self::Output {
+        BVec4 { x: self.x ^ other.x,y: self.y ^ other.y,z: self.z ^ other.z,w: self.w ^ other.w, }
+    }
+}
+
+// mask ^= mask
+impl BitXorAssign for BVec4 {
+    fn bitxor_assign(&mut self,other: Self) {
+        self.x ^= other.x;
+        self.y ^= other.y;
+        self.z ^= other.z;
+        self.w ^= other.w;
+    }
+}
+
+// !mask
+impl Not for BVec4 {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        BVec4 { x: !self.x,y: !self.y,z: !self.z,w: !self.w, }
+    }
+}
+
+impl<T> Vec4<T> {
+
+    /// pick components from `a` where `mask` is `true`, and from `b` where it is `false`.
+    pub fn select(mask: BVec4,a: Self,b: Self) -> Self {
+        Vec4 {
+            x: if mask.x { a.x } else { b.x },
+            y: if mask.y { a.y } else { b.y },
+            z: if mask.z { a.z } else { b.z },
+            w: if mask.w { a.w } else { b.w },
+        }
+    }
+}
+
+impl<T: PartialOrd> Vec4<T> {
+
+    /// component-wise equal to.
+    pub fn cmpeq(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x == other.x,y: self.y == other.y,z: self.z == other.z,w: self.w == other.w, }
+    }
+
+    /// component-wise not equal to.
+    pub fn cmpne(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x != other.x,y: self.y != other.y,z: self.z != other.z,w: self.w != other.w, }
+    }
+
+    /// component-wise less than.
+    pub fn cmplt(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x < other.x,y: self.y < other.y,z: self.z < other.z,w: self.w < other.w, }
+    }
+
+    /// component-wise less than or equal to.
+    pub fn cmple(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x <= other.x,y: self.y <= other.y,z: self.z <= other.z,w: self.w <= other.w, }
+    }
+
+    /// component-wise greater than.
+    pub fn cmpgt(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x > other.x,y: self.y > other.y,z: self.z > other.z,w: self.w > other.w, }
+    }
+
+    /// component-wise greater than or equal to.
+    pub fn cmpge(self,other: Self) -> BVec4 {
+        BVec4 { x: self.x >= other.x,y: self.y >= other.y,z: self.z >= other.z,w: self.w >= other.w, }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks() {
+        let a = Vec4 { x: 1,y: 5,z: -2,w: 0, };
+        let b = Vec4 { x: 3,y: 5,z: -4,w: 0, };
+        assert_eq!(a.cmplt(b),BVec4 { x: true,y: false,z: false,w: false, });
+        assert_eq!(a.cmple(b),BVec4 { x: true,y: true,z: false,w: true, });
+        assert_eq!(a.cmpeq(b),BVec4 { x: false,y: true,z: false,w: true, });
+        assert_eq!(a.cmpne(b),BVec4 { x: true,y: false,z: true,w: false, });
+        assert_eq!(a.cmpgt(b),BVec4 { x: false,y: false,z: true,w: false, });
+        assert_eq!(a.cmpge(b),BVec4 { x: false,y: true,z: true,w: true, });
+        assert_eq!(a.cmplt(b) | a.cmpeq(b),a.cmple(b));
+        assert_eq!(a.cmple(b) & a.cmpge(b),a.cmpeq(b));
+        assert_eq!(a.cmplt(b) ^ a.cmple(b),a.cmpeq(b));
+        assert_eq!(!a.cmpeq(b),a.cmpne(b));
+        assert_eq!(Vec4::select(a.cmplt(b),a,b),Vec4 { x: 1,y: 5,z: -4,w: 0, });
+        assert_eq!(Vec4::select(a.cmpgt(b),a,b),Vec4 { x: 3,y: 5,z: -2,w: 0, });
+    }
+
+    #[test]
+    fn any_all() {
+        assert!(BVec4 { x: true,y: true,z: true,w: true, }.all());
+        assert!(!BVec4 { x: false,y: false,z: false,w: false, }.any());
+        let mixed = BVec4 { x: true,y: false,z: false,w: false, };
+        assert!(mixed.any() && !mixed.all());
+        let mut mask = mixed;
+        mask |= !mixed;
+        assert!(mask.all());
+        mask &= mixed;
+        assert_eq!(mask,mixed);
+        mask ^= mixed;
+        assert!(!mask.any());
+    }
+}
@@ -0,0 +1,179 @@
+use {
+    crate::*,
+    std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+    },
+};
+
+/// maximum number of Jacobi sweeps before giving up on further convergence.
+const MAX_SWEEPS: usize = 64;
+
+/// Eigen-decomposition of a symmetric matrix.
+///
+/// A symmetric matrix `A` can be written as `A = V * D * V^T`, where `D` is diagonal and `V` is orthonormal. The
+/// diagonal of `D` holds the eigenvalues, and the columns of `V` the matching eigenvectors. Typical uses are principal
+/// axes of inertia tensors and covariance matrices (PCA).
+///
+/// Create one with [`Matrix::symmetric_eigen`]; [`Mat3x3::symmetric_eigen`] returns the same as a [`Vec3`] and a
+/// [`Mat3x3`].
+#[derive(Copy,Clone,Debug)]
+pub struct SymmetricEigen<T,const N: usize> {
+    /// eigenvalues, from largest to smallest.
+    pub eigenvalues: VecN<T,N>,
+    /// eigenvectors as columns, in the same order as the eigenvalues.
+    pub eigenvectors: Matrix<T,N,N>,
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float,const N: usize> SymmetricEigen<T,N> {
+
+    /// decompose `matrix` with the cyclic Jacobi method.
+    ///
+    /// Only the symmetric part of `matrix` is meaningful; the result for non-symmetric input is undefined.
+    pub fn new(matrix: Matrix<T,N,N>) -> Self {
+        let mut a = matrix;
+        let mut v = Matrix::<T,N,N>::IDENTITY;
+
+        // total size, to decide when the off-diagonal part is negligible
+        let mut total = T::ZERO;
+        for e in a.0.iter().flatten() {
+            total = total + *e * *e;
+        }
+        let threshold = T::EPSILON * T::EPSILON * total;
+
+        for _ in 0..MAX_SWEEPS {
+
+            // stop once the off-diagonal elements are gone
+            let mut off = T::ZERO;
+            for p in 0..N {
+                for q in p + 1..N {
+                    off = off + a[(p,q)] * a[(p,q)];
+                }
+            }
+            if off <= threshold {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[(p,q)] == T::ZERO {
+                        continue;
+                    }
+
+                    // rotation that zeroes a[(p,q)]
+                    let theta = (a[(q,q)] - a[(p,p)]) / ((T::ONE + T::ONE) * a[(p,q)]);
+                    let t = T::ONE / (theta.abs() + (theta * theta + T::ONE).sqrt());
+                    let t = if theta < T::ZERO { -t } else { t };
+                    let c = T::ONE / (t * t + T::ONE).sqrt();
+                    let s = t * c;
+
+                    // A = J^T * A * J
+                    for k in 0..N {
+                        let akp = a[(k,p)];
+                        let akq = a[(k,q)];
+                        a[(k,p)] = c * akp - s * akq;
+                        a[(k,q)] = s * akp + c * akq;
+                    }
+                    for k in 0..N {
+                        let apk = a[(p,k)];
+                        let aqk = a[(q,k)];
+                        a[(p,k)] = c * apk - s * aqk;
+                        a[(q,k)] = s * apk + c * aqk;
+                    }
+
+                    // V = V * J
+                    for k in 0..N {
+                        let vkp = v[(k,p)];
+                        let vkq = v[(k,q)];
+                        v[(k,p)] = c * vkp - s * vkq;
+                        v[(k,q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        // sort from largest to smallest eigenvalue
+        let mut eigenvalues: [T; N] = std::array::from_fn(|i| a[(i,i)]);
+        for i in 0..N {
+            let mut m = i;
+            for j in i + 1..N {
+                if eigenvalues[j] > eigenvalues[m] {
+                    m = j;
+                }
+            }
+            if m != i {
+                eigenvalues.swap(i,m);
+                v.0.swap(i,m);
+            }
+        }
+
+        SymmetricEigen {
+            eigenvalues: VecN(eigenvalues),
+            eigenvectors: v,
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float,const N: usize> Matrix<T,N,N> {
+
+    /// eigenvalues and eigenvectors of a symmetric matrix.
+    pub fn symmetric_eigen(self) -> SymmetricEigen<T,N> {
+        SymmetricEigen::new(self)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat3x3<T> {
+
+    /// eigenvalues (from largest to smallest) and eigenvectors (as columns, in the same order) of a symmetric matrix.
+    pub fn symmetric_eigen(self) -> (Vec3<T>,Mat3x3<T>) {
+        let eigen = SymmetricEigen::new(self.into());
+        (eigen.eigenvalues.into(),eigen.eigenvectors.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn check<const N: usize>(a: Matrix<f64,N,N>) {
+        let eigen = a.symmetric_eigen();
+        let v = eigen.eigenvectors;
+
+        // orthonormal eigenvectors
+        assert!(close(v.transpose() * v,Matrix::IDENTITY));
+
+        // A * v = lambda * v, sorted from largest to smallest
+        for i in 0..N {
+            let column = VecN(v.0[i]);
+            assert!(close(a * column,column * eigen.eigenvalues[i]));
+            if i > 0 {
+                assert!(eigen.eigenvalues[i] <= eigen.eigenvalues[i - 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn reconstruction() {
+        check(Matrix::from([[4.0,1.0,-2.0,0.5],[1.0,3.0,0.0,1.0],[-2.0,0.0,5.0,-1.0],[0.5,1.0,-1.0,2.0]]));
+        let (values,vectors) = Mat3x3::from([2.0,-1.0,0.0,-1.0,2.0,-1.0,0.0,-1.0,2.0]).symmetric_eigen();
+        let expected = [2.0 + 2f64.sqrt(),2.0,2.0 - 2f64.sqrt()];
+        assert!((values.x - expected[0]).abs() < 1e-12);
+        assert!((values.y - expected[1]).abs() < 1e-12);
+        assert!((values.z - expected[2]).abs() < 1e-12);
+        assert!((vectors.determinant().abs() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn degenerate() {
+        // repeated eigenvalues
+        check(Matrix::from([[2.0,0.0,0.0],[0.0,2.0,0.0],[0.0,0.0,1.0]]));
+        check(Matrix::from([[1.0,1.0,1.0],[1.0,1.0,1.0],[1.0,1.0,1.0]]));
+        check(Matrix::<f64,3,3>::ZERO);
+    }
+}
@@ -0,0 +1,369 @@
+use {
+    crate::*,
+    std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+    },
+};
+
+/// degree of the Padé approximant for the matrix exponential.
+const PADE_DEGREE: usize = 6;
+
+/// maximum number of square roots taken by the matrix logarithm.
+const MAX_SQUARE_ROOTS: usize = 64;
+
+/// maximum number of iterations for one square root, and for the logarithm series.
+const MAX_ITERATIONS: usize = 64;
+
+/// small integer as a scalar.
+fn scalar<T: Zero + One + Add<Output=T>>(n: usize) -> T {
+    let mut result = T::ZERO;
+    for _ in 0..n {
+        result = result + T::ONE;
+    }
+    result
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float,const N: usize> Matrix<T,N,N> {
+
+    /// matrix exponential, by scaling and squaring with a Padé approximant.
+    ///
+    /// A matrix with infinite or NaN elements gives a matrix of NaN.
+    pub fn exp(self) -> Self {
+        let norm = self.norm_1();
+        if !norm.is_finite() {
+            return Self::from_fn(|_,_| T::NAN);
+        }
+
+        // scale by 2^-squarings, so that the norm drops to at most 1/2 where the approximant is accurate
+        let half = T::ONE / (T::ONE + T::ONE);
+        let squarings = if norm > half {
+            (norm.log2() + T::ONE).ceil()
+        }
+        else {
+            T::ZERO
+        };
+        let a = self * (-squarings).exp2();
+
+        // numerator and denominator of the Padé approximant
+        let q = PADE_DEGREE;
+        let mut c = T::ONE;
+        let mut x = Self::IDENTITY;
+        let mut n = Self::IDENTITY;
+        let mut d = Self::IDENTITY;
+        for k in 1..=q {
+            c = c * scalar(q - k + 1) / (scalar::<T>(2 * q - k + 1) * scalar(k));
+            x = a * x;
+            n = n + x * c;
+            if k % 2 == 0 {
+                d = d + x * c;
+            }
+            else {
+                d = d - x * c;
+            }
+        }
+
+        // d is always invertible for the scaled matrix
+        let mut f = d.lu().solve_matrix(n).unwrap_or(n);
+
+        // undo the scaling
+        let mut k = T::ZERO;
+        while k < squarings {
+            f *= f;
+            k = k + T::ONE;
+        }
+        f
+    }
+
+    /// principal square root by the Denman-Beavers iteration, or `None` if it does not converge.
+    fn sqrt(self) -> Option<Self> {
+        let mut y = self;
+        let mut z = Self::IDENTITY;
+        let half = T::ONE / (T::ONE + T::ONE);
+        for _ in 0..MAX_ITERATIONS {
+            let yi = y.lu().inverse()?;
+            let zi = z.lu().inverse()?;
+            let next = (y + zi) * half;
+            z = (z + yi) * half;
+            let change = (next - y).norm_1();
+            y = next;
+            if change <= T::EPSILON * y.norm_1() {
+                return Some(y);
+            }
+        }
+        None
+    }
+
+    /// principal matrix logarithm, or `None` if it has no real logarithm (like a singular matrix, or one with negative
+    /// real eigenvalues).
+    ///
+    /// Uses inverse scaling and squaring: repeated square roots bring the matrix close to the identity, where the
+    /// series `log(A) = 2 * atanh((A - I) * (A + I)^-1)` converges quickly.
+    pub fn log(self) -> Option<Self> {
+        let quarter = T::ONE / scalar(4);
+        let mut a = self;
+        let mut roots = 0;
+        while (a - Self::IDENTITY).norm_1() > quarter {
+            if roots == MAX_SQUARE_ROOTS {
+                return None;
+            }
+            a = a.sqrt()?;
+            roots += 1;
+        }
+
+        // series in z = (A - I) * (A + I)^-1
+        let z = (a + Self::IDENTITY).lu().solve_matrix(a - Self::IDENTITY)?;
+        let z2 = z * z;
+        let mut term = z;
+        let mut sum = z;
+        let mut k = 1;
+        let mut converged = false;
+        for _ in 0..MAX_ITERATIONS {
+            term *= z2;
+            k += 2;
+            let next = sum + term / scalar(k);
+            converged = (next - sum).norm_1() <= T::EPSILON * sum.norm_1();
+            sum = next;
+            if converged {
+                break;
+            }
+        }
+        if !converged {
+            return None;
+        }
+
+        // undo the square roots
+        let mut l = sum * (T::ONE + T::ONE);
+        for _ in 0..roots {
+            l = l * (T::ONE + T::ONE);
+        }
+        Some(l)
+    }
+}
+
+/// coefficients `(sin θ / θ, (1 - cos θ) / θ², (θ - sin θ) / θ³)` of the closed-form exponentials.
+fn rodrigues<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float>(theta: T) -> (T,T,T) {
+    let two = T::ONE + T::ONE;
+    let theta2 = theta * theta;
+    if theta < T::EPSILON.sqrt().sqrt().sqrt() {
+        // Taylor series, avoiding the cancellation
+        let a = T::ONE - theta2 / scalar(6) + theta2 * theta2 / scalar(120);
+        let b = T::ONE / two - theta2 / scalar(24) + theta2 * theta2 / scalar(720);
+        let c = T::ONE / scalar(6) - theta2 / scalar(120) + theta2 * theta2 / scalar(5040);
+        (a,b,c)
+    }
+    else {
+        let (s,_) = theta.sin_cos();
+        let h = (theta / two).sin();
+        (s / theta,two * h * h / theta2,(theta - s) / (theta2 * theta))
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat3x3<T> {
+
+    /// skew-symmetric cross product matrix, so that `Mat3x3::skew(a) * b` = `a.cross(b)`.
+    pub fn skew(v: Vec3<T>) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: T::ZERO,y: v.z,z: -v.y, },
+            y: Vec3 { x: -v.z,y: T::ZERO,z: v.x, },
+            z: Vec3 { x: v.y,y: -v.x,z: T::ZERO, },
+        }
+    }
+
+    /// whether the matrix is exactly skew-symmetric.
+    fn is_skew(self) -> bool {
+        (self.x.x == T::ZERO) && (self.y.y == T::ZERO) && (self.z.z == T::ZERO) &&
+        (self.x.y == -self.y.x) && (self.x.z == -self.z.x) && (self.y.z == -self.z.y)
+    }
+
+    /// whether the matrix is a rotation, up to rounding errors.
+    fn is_rotation(self) -> bool {
+        let tolerance = scalar::<T>(16) * T::EPSILON;
+        let e = self.transpose() * self - Mat3x3::IDENTITY;
+        [e.x,e.y,e.z].iter().all(|c| (c.x.abs() <= tolerance) && (c.y.abs() <= tolerance) && (c.z.abs() <= tolerance)) &&
+            (self.determinant() > T::ZERO)
+    }
+
+    /// rotation matrix of the rotation vector `omega` (axis times angle in radians), the closed-form exponential of
+    /// `Mat3x3::skew(omega)`.
+    pub fn exp_skew(omega: Vec3<T>) -> Self {
+        let (a,b,_) = rodrigues(omega.length());
+        let k = Mat3x3::skew(omega);
+        Mat3x3::IDENTITY + k * a + k * k * b
+    }
+
+    /// rotation vector (axis times angle in radians) of a rotation matrix, the closed-form logarithm.
+    pub fn log_rotation(self) -> Vec3<T> {
+        let mut q = Quaternion::from(self);
+        if q.r < T::ZERO {
+            q = -q;
+        }
+        let v = Vec3 { x: q.i,y: q.j,z: q.k, };
+        let s = v.length();
+        if s == T::ZERO {
+            return Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, };
+        }
+        v * ((T::ONE + T::ONE) * s.atan2(q.r) / s)
+    }
+
+    /// matrix exponential.
+    ///
+    /// Skew-symmetric matrices use the closed form of [`Mat3x3::exp_skew`], everything else uses scaling and squaring
+    /// with a Padé approximant.
+    pub fn exp(self) -> Self {
+        if self.is_skew() {
+            return Mat3x3::exp_skew(Vec3 { x: self.y.z,y: self.z.x,z: self.x.y, });
+        }
+        Matrix::from(self).exp().into()
+    }
+
+    /// principal matrix logarithm, or `None` if there is no real logarithm.
+    ///
+    /// Rotations use the closed form of [`Mat3x3::log_rotation`], everything else uses inverse scaling and squaring.
+    pub fn log(self) -> Option<Self> {
+        if self.is_rotation() {
+            return Some(Mat3x3::skew(self.log_rotation()));
+        }
+        Matrix::from(self).log().map(|m| m.into())
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat4x4<T> {
+
+    /// rigid body transformation of the twist with angular velocity `omega` and linear velocity `velocity`, the
+    /// closed-form exponential of the matrix with `Mat3x3::skew(omega)` in the upper left and `velocity` in the last
+    /// column.
+    pub fn exp_twist(omega: Vec3<T>,velocity: Vec3<T>) -> Self {
+        let (a,b,c) = rodrigues(omega.length());
+        let k = Mat3x3::skew(omega);
+        let k2 = k * k;
+        let rotation = Mat3x3::IDENTITY + k * a + k2 * b;
+        let v = Mat3x3::IDENTITY + k * b + k2 * c;
+        Mat4x4::from_linear(rotation,v * velocity)
+    }
+
+    /// angular and linear velocity of the twist that generates a rigid body transformation, the closed-form logarithm.
+    pub fn log_rigid(self) -> (Vec3<T>,Vec3<T>) {
+        let omega = self.linear().log_rotation();
+        let theta = omega.length();
+        let (a,b,_) = rodrigues(theta);
+        let two = T::ONE + T::ONE;
+
+        // coefficient of k² in the inverse of v
+        let d = if theta < T::EPSILON.sqrt().sqrt().sqrt() {
+            let theta2 = theta * theta;
+            T::ONE / scalar(12) + theta2 / scalar(720) + theta2 * theta2 / scalar(30240)
+        }
+        else {
+            (T::ONE - a / (two * b)) / (theta * theta)
+        };
+        let k = Mat3x3::skew(omega);
+        let vi = Mat3x3::IDENTITY - k / two + k * k * d;
+        (omega,vi * Vec3 { x: self.w.x,y: self.w.y,z: self.w.z, })
+    }
+
+    /// matrix exponential.
+    ///
+    /// Twists (skew-symmetric upper left 3x3 and zero bottom row) use the closed form of [`Mat4x4::exp_twist`],
+    /// everything else uses scaling and squaring with a Padé approximant.
+    pub fn exp(self) -> Self {
+        let bottom = (self.x.w == T::ZERO) && (self.y.w == T::ZERO) && (self.z.w == T::ZERO) && (self.w.w == T::ZERO);
+        if bottom && self.linear().is_skew() {
+            return Mat4x4::exp_twist(
+                Vec3 { x: self.y.z,y: self.z.x,z: self.x.y, },
+                Vec3 { x: self.w.x,y: self.w.y,z: self.w.z, },
+            );
+        }
+        Matrix::from(self).exp().into()
+    }
+
+    /// principal matrix logarithm, or `None` if there is no real logarithm.
+    ///
+    /// Rigid body transformations use the closed form of [`Mat4x4::log_rigid`], everything else uses inverse scaling
+    /// and squaring.
+    pub fn log(self) -> Option<Self> {
+        let bottom = (self.x.w == T::ZERO) && (self.y.w == T::ZERO) && (self.z.w == T::ZERO) && (self.w.w == T::ONE);
+        if bottom && self.linear().is_rotation() {
+            let (omega,velocity) = self.log_rigid();
+            let mut m = Mat4x4::from_linear(Mat3x3::skew(omega),velocity);
+            m.w.w = T::ZERO;
+            return Some(m);
+        }
+        Matrix::from(self).log().map(|m| m.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    #[test]
+    fn exp_of_zero_and_diagonal() {
+        assert!(Mat3x3::<f64>::ZERO.exp() == Mat3x3::IDENTITY);
+        let d = Mat3x3::from_diagonal(Vec3 { x: 1.0,y: -2.0,z: 0.5, }).exp();
+        let expected = Mat3x3::from_diagonal(Vec3 { x: 1f64.exp(),y: (-2f64).exp(),z: 0.5f64.exp(), });
+        assert!(close_within(d,expected,1e-13));
+    }
+
+    #[test]
+    fn exp_of_infinite_is_nan() {
+        let mut m = Mat3x3::<f64>::IDENTITY;
+        m.y.x = f64::INFINITY;
+        assert!(m.exp().iter().all(|e| e.is_nan()));
+    }
+
+    #[test]
+    fn skew_round_trip() {
+        let omega = Vec3 { x: 0.3,y: -1.2,z: 2.0, };
+        let k = Mat3x3::skew(omega);
+        let r = k.exp();
+        let pade: Mat3x3<f64> = Matrix::from(k).exp().into();
+        assert!(close(r,pade));
+        assert!((r.log_rotation() - omega).length() < 1e-12);
+        assert!(close(r.log().unwrap(),k));
+
+        // small angles
+        let omega = Vec3 { x: 1e-9,y: 0.0,z: 2e-9, };
+        assert!((Mat3x3::exp_skew(omega).log_rotation() - omega).length() < 1e-20);
+    }
+
+    #[test]
+    fn twist_round_trip() {
+        let omega = Vec3 { x: 0.3,y: -1.2,z: 2.0, };
+        let velocity = Vec3 { x: 1.0,y: -0.5,z: 0.25, };
+        let mut twist = Mat4x4::from_linear(Mat3x3::skew(omega),velocity);
+        twist.w.w = 0.0;
+        let g = twist.exp();
+        assert!(g == Mat4x4::exp_twist(omega,velocity));
+        let pade: Mat4x4<f64> = Matrix::from(twist).exp().into();
+        assert!(close(g,pade));
+        let (o,v) = g.log_rigid();
+        assert!((o - omega).length() < 1e-12);
+        assert!((v - velocity).length() < 1e-12);
+        assert!(close(g.log().unwrap(),twist));
+    }
+
+    #[test]
+    fn generic_round_trip() {
+        let a = Matrix::<f64,5,5>::from_fn(|r,c| if r == c { 2.0 } else { 0.1 / (1.0 + r as f64 + c as f64) });
+        assert!(close(a.log().unwrap().exp(),a));
+        let b = Matrix::<f64,3,3>::from([[0.3,-0.2,0.5],[0.1,0.2,-0.4],[0.6,0.1,-0.3]]);
+        assert!(close(b.exp().log().unwrap(),b));
+        assert!(close_within((b * 5.0).exp() * (b * -5.0).exp(),Matrix::IDENTITY,1e-9));
+    }
+
+    #[test]
+    fn log_without_real_logarithm() {
+        let negative = Mat3x3::from_diagonal(Vec3 { x: -1.0,y: -2.0,z: 3.0, });
+        assert!(negative.log().is_none());
+        let singular = Mat3x3::from_diagonal(Vec3 { x: 1.0,y: 0.0,z: 3.0, });
+        assert!(singular.log().is_none());
+        assert!(Mat3x3::<f64>::ZERO.log().is_none());
+    }
+}
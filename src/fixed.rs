@@ -32,6 +32,7 @@ use {
 /// 
 /// To make a `Fixed` number, specify the underlying integer as well as the number of fractional bits. `Fixed<i32,16>` creates
 /// a 16:16 fixed point number, `Fixed<i128,8>` creates a 120:8 fixed point number, etc.
+#[derive(Copy,Clone,Debug)]
 pub struct Fixed<T,const B: usize>(T);
 
 impl<T,const B: usize> Fixed<T,B> {
@@ -76,6 +76,7 @@ pub use fixed::*;
 mod complex;
 pub use complex::*;
 
+#[macro_use]
 mod vector;
 pub use vector::*;
 
@@ -88,6 +89,15 @@ pub use vec3::*;
 mod vec4;
 pub use vec4::*;
 
+mod bvec2;
+pub use bvec2::*;
+
+mod bvec3;
+pub use bvec3::*;
+
+mod bvec4;
+pub use bvec4::*;
+
 mod matrix;
 pub use matrix::*;
 
@@ -100,6 +110,40 @@ pub use mat3x3::*;
 mod mat4x4;
 pub use mat4x4::*;
 
+mod mat2x3;
+pub use mat2x3::*;
+
+mod mat3x2;
+pub use mat3x2::*;
+
+mod mat3x4;
+pub use mat3x4::*;
+
+mod mat4x3;
+pub use mat4x3::*;
+
+#[macro_use]
+mod affine;
+
+mod affine2;
+pub use affine2::*;
+
+mod affine3;
+pub use affine3::*;
+
+mod lu;
+pub use lu::*;
+
+mod qr;
+pub use qr::*;
+
+mod eigen;
+pub use eigen::*;
+
+mod svd;
+
+mod exponential;
+
 mod quaternion;
 pub use quaternion::*;
 
@@ -117,3 +161,6 @@ pub use multivec3::*;
 
 mod multivec4;
 pub use multivec4::*;
+
+#[cfg(test)]
+mod testing;
@@ -0,0 +1,275 @@
+use {
+    crate::*,
+    std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+    },
+};
+
+/// LU decomposition with partial pivoting.
+///
+/// Factors a square matrix `A` as `P * A = L * U`, where `P` is a row permutation, `L` is lower triangular with ones on
+/// the diagonal, and `U` is upper triangular. Once factored, the same decomposition can solve `A * x = b` for any number
+/// of right-hand sides, which is both faster and more accurate than going through the inverse.
+///
+/// Create one with [`Matrix::lu`], [`Mat3x3::lu`] or [`Mat4x4::lu`].
+#[derive(Copy,Clone,Debug)]
+pub struct Lu<T,const N: usize> {
+    /// `L` below the diagonal and `U` on and above it.
+    lu: Matrix<T,N,N>,
+    /// original row index of each row of the factorization.
+    pivots: [usize; N],
+    /// whether `P` is an odd permutation.
+    odd: bool,
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real,const N: usize> Lu<T,N> {
+
+    /// factor `matrix`.
+    pub fn new(matrix: Matrix<T,N,N>) -> Self {
+        let mut lu = matrix;
+        let mut pivots: [usize; N] = std::array::from_fn(|i| i);
+        let mut odd = false;
+        for k in 0..N {
+
+            // find the largest pivot in this column
+            let mut p = k;
+            for i in k + 1..N {
+                if lu[(i,k)].abs() > lu[(p,k)].abs() {
+                    p = i;
+                }
+            }
+            if lu[(p,k)] == T::ZERO {
+                continue;
+            }
+
+            // move it onto the diagonal
+            if p != k {
+                for c in 0..N {
+                    lu.0[c].swap(p,k);
+                }
+                pivots.swap(p,k);
+                odd = !odd;
+            }
+
+            // eliminate below the pivot
+            let pivot = lu[(k,k)];
+            for i in k + 1..N {
+                let f = lu[(i,k)] / pivot;
+                lu[(i,k)] = f;
+                for j in k + 1..N {
+                    lu[(i,j)] = lu[(i,j)] - f * lu[(k,j)];
+                }
+            }
+        }
+        Lu { lu,pivots,odd, }
+    }
+
+    /// lower triangular factor `L`, with ones on the diagonal.
+    pub fn l(&self) -> Matrix<T,N,N> {
+        Matrix::from_fn(|r,c| if r > c { self.lu[(r,c)] } else if r == c { T::ONE } else { T::ZERO })
+    }
+
+    /// upper triangular factor `U`.
+    pub fn u(&self) -> Matrix<T,N,N> {
+        Matrix::from_fn(|r,c| if r <= c { self.lu[(r,c)] } else { T::ZERO })
+    }
+
+    /// row permutation `P`, as the original row index of each row of `L * U`.
+    pub fn pivots(&self) -> [usize; N] {
+        self.pivots
+    }
+
+    /// whether the factored matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.lu[(i,i)] == T::ZERO)
+    }
+
+    /// determinant of the factored matrix.
+    pub fn determinant(&self) -> T {
+        let mut det = T::ONE;
+        for i in 0..N {
+            det = det * self.lu[(i,i)];
+        }
+        if self.odd {
+            -det
+        } else {
+            det
+        }
+    }
+
+    /// solve `A * x = b` for `x`, or `None` if the matrix is singular.
+    ///
+    /// `b` can be a [`VecN`] or any vector that converts to and from one, like [`Vec3`] or [`Vec4`].
+    pub fn solve<V: Into<VecN<T,N>> + From<VecN<T,N>>>(&self,b: V) -> Option<V> {
+        if self.is_singular() {
+            return None;
+        }
+        let b = b.into();
+        let mut x: [T; N] = std::array::from_fn(|i| b.0[self.pivots[i]]);
+
+        // forward substitution with L
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[(i,j)] * x[j];
+            }
+        }
+
+        // back substitution with U
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] = x[i] - self.lu[(i,j)] * x[j];
+            }
+            x[i] = x[i] / self.lu[(i,i)];
+        }
+
+        Some(VecN(x).into())
+    }
+
+    /// solve `A * X = B` for all `K` columns of `B` at once, or `None` if the matrix is singular.
+    pub fn solve_matrix<const K: usize>(&self,b: Matrix<T,N,K>) -> Option<Matrix<T,N,K>> {
+        if self.is_singular() {
+            return None;
+        }
+        let mut x = b;
+        for column in x.0.iter_mut() {
+            *column = self.solve(VecN(*column))?.0;
+        }
+        Some(x)
+    }
+
+    /// inverse of the factored matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<T,N,N>> {
+        self.solve_matrix(Matrix::IDENTITY)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real,const N: usize> Matrix<T,N,N> {
+
+    /// LU decomposition with partial pivoting.
+    pub fn lu(self) -> Lu<T,N> {
+        Lu::new(self)
+    }
+
+    /// solve `self * x = b` for `x`, or `None` if the matrix is singular.
+    ///
+    /// To solve for several right-hand sides, factor once with [`Matrix::lu`] instead.
+    pub fn solve(self,b: VecN<T,N>) -> Option<VecN<T,N>> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float,const N: usize> Matrix<T,N,N> {
+
+    /// 1-norm (largest absolute column sum).
+    pub(crate) fn norm_1(self) -> T {
+        let mut norm = T::ZERO;
+        for column in self.0.iter() {
+            let mut sum = T::ZERO;
+            for e in column.iter() {
+                sum = sum + e.abs();
+            }
+            norm = norm.max(sum);
+        }
+        norm
+    }
+
+    /// condition number estimate in the 1-norm, or infinity if the matrix cannot be inverted.
+    pub fn condition_number(self) -> T {
+        match self.lu().inverse() {
+            Some(inverse) => self.norm_1() * inverse.norm_1(),
+            None => T::INFINITY,
+        }
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix is singular or too close to singular.
+    ///
+    /// The matrix counts as near-singular when the reciprocal of its condition number estimate drops below
+    /// `tolerance`. Something like `T::EPSILON` scaled by a safety factor is a reasonable choice.
+    pub fn inverse_with_tolerance(self,tolerance: T) -> std::result::Result<Self,SingularMatrixError> {
+        let inverse = self.lu().inverse().ok_or(SingularMatrixError)?;
+        let condition = self.norm_1() * inverse.norm_1();
+        if !condition.is_finite() || (T::ONE / condition < tolerance) {
+            return Err(SingularMatrixError);
+        }
+        Ok(inverse)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real> Mat3x3<T> {
+
+    /// LU decomposition with partial pivoting.
+    pub fn lu(self) -> Lu<T,3> {
+        Lu::new(self.into())
+    }
+
+    /// solve `self * x = b` for `x`, or `None` if the matrix is singular.
+    ///
+    /// To solve for several right-hand sides, factor once with [`Mat3x3::lu`] instead.
+    pub fn solve(self,b: Vec3<T>) -> Option<Vec3<T>> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real> Mat4x4<T> {
+
+    /// LU decomposition with partial pivoting.
+    pub fn lu(self) -> Lu<T,4> {
+        Lu::new(self.into())
+    }
+
+    /// solve `self * x = b` for `x`, or `None` if the matrix is singular.
+    ///
+    /// To solve for several right-hand sides, factor once with [`Mat4x4::lu`] instead.
+    pub fn solve(self,b: Vec4<T>) -> Option<Vec4<T>> {
+        self.lu().solve(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn matrix() -> Matrix<f64,4,4> {
+        Matrix::from([[0.0,2.0,1.0,-1.0],[1.0,1.0,0.5,3.0],[4.0,-2.0,2.0,1.0],[1.0,0.0,-3.0,2.0]])
+    }
+
+    #[test]
+    fn reconstruction() {
+        let a = matrix();
+        let lu = a.lu();
+        let product = lu.l() * lu.u();
+        let pivots = lu.pivots();
+        for r in 0..4 {
+            for c in 0..4 {
+                assert!((product[(r,c)] - a[(pivots[r],c)]).abs() < 1e-12);
+            }
+        }
+        assert!((lu.determinant() - Mat4x4::from(a).determinant()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn solve() {
+        let a = matrix();
+        let b = VecN([1.0,-2.0,0.5,3.0]);
+        let x = a.solve(b).unwrap();
+        assert!(close(a * x,b));
+        assert!(close(a * a.lu().inverse().unwrap(),Matrix::IDENTITY));
+    }
+
+    #[test]
+    fn singular() {
+        let a = Mat3x3::from([1.0,2.0,3.0,2.0,4.0,6.0,0.0,1.0,1.0]);
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(),0.0);
+        assert!(a.solve(Vec3 { x: 1.0,y: 2.0,z: 3.0, }).is_none());
+        assert!(lu.inverse().is_none());
+    }
+}
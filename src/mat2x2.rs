@@ -17,18 +17,42 @@ use {
             MulAssign,
             DivAssign,
             Neg,
+            Index,
+            IndexMut,
         },
     },
 };
 
+/// 2x2 matrix template.
+///
+/// The matrix is stored as two column vectors `x` and `y`, so `m.y.x` is the element in the first row of the second
+/// column. A matrix transforms a vector by `m * v`, and `a * b` applies `b` first.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
 pub struct Mat2x2<T> {
     pub x: Vec2<T>,
     pub y: Vec2<T>,
 }
 
-/*
-impl<T: Zero + Add<T,Output=T> + Mul<T,Output=T> + Div<T,Output=T> + Neg<Output=T> + PartialEq> Mat2x2<T> {
+impl<T: Zero + One> Mat2x2<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat2x2 {
+        x: Vec2 { x: T::ZERO,y: T::ZERO, },
+        y: Vec2 { x: T::ZERO,y: T::ZERO, },
+    };
+
+    /// identity matrix.
+    pub const IDENTITY: Self = Mat2x2 {
+        x: Vec2 { x: T::ONE,y: T::ZERO, },
+        y: Vec2 { x: T::ZERO,y: T::ONE, },
+    };
+}
+
+impl<T: Copy> Mat2x2<T> {
+
+    /// transpose.
     pub fn transpose(self) -> Mat2x2<T> {
         Mat2x2 {
             x: Vec2 { x: self.x.x,y: self.y.x, },
@@ -36,6 +60,81 @@ impl<T: Zero + Add<T,Output=T> + Mul<T,Output=T> + Div<T,Output=T> + Neg<Output=
         }
     }
 
+    /// build a matrix from `f(row,col)` for each element.
+    pub fn from_fn<F: FnMut(usize,usize) -> T>(mut f: F) -> Self {
+        Mat2x2 {
+            x: Vec2 { x: f(0,0),y: f(1,0), },
+            y: Vec2 { x: f(0,1),y: f(1,1), },
+        }
+    }
+
+    /// apply `f` to each element.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Mat2x2<U> {
+        Mat2x2 { x: self.x.map(&mut f),y: self.y.map(&mut f), }
+    }
+
+    /// row `i`.
+    pub fn row(self,i: usize) -> Vec2<T> {
+        Vec2 { x: self[(i,0)],y: self[(i,1)], }
+    }
+
+    /// column `j`.
+    pub fn col(self,j: usize) -> Vec2<T> {
+        Vec2 { x: self[(0,j)],y: self[(1,j)], }
+    }
+
+    /// replace row `i`.
+    pub fn set_row(&mut self,i: usize,row: Vec2<T>) {
+        self[(i,0)] = row.x;
+        self[(i,1)] = row.y;
+    }
+
+    /// replace column `j`.
+    pub fn set_col(&mut self,j: usize,col: Vec2<T>) {
+        self[(0,j)] = col.x;
+        self[(1,j)] = col.y;
+    }
+
+    /// main diagonal.
+    pub fn diagonal(self) -> Vec2<T> {
+        Vec2 { x: self.x.x,y: self.y.y, }
+    }
+
+    /// iterate over the elements, column by column.
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        IntoIterator::into_iter([
+            &self.x.x,&self.x.y,
+            &self.y.x,&self.y.y,
+        ])
+    }
+}
+
+impl<T: Copy + Zero> Mat2x2<T> {
+
+    /// diagonal (scale) matrix with `diagonal` on the main diagonal.
+    pub fn from_diagonal(diagonal: Vec2<T>) -> Self {
+        Mat2x2 {
+            x: Vec2 { x: diagonal.x,y: T::ZERO, },
+            y: Vec2 { x: T::ZERO,y: diagonal.y, },
+        }
+    }
+}
+
+impl<T: Copy + Real + Neg<Output=T>> Mat2x2<T> {
+
+    /// counter-clockwise rotation matrix, `angle` in radians.
+    pub fn from_angle(angle: T) -> Self {
+        let (s,c) = angle.sin_cos();
+        Mat2x2 {
+            x: Vec2 { x: c,y: s, },
+            y: Vec2 { x: -s,y: c, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + PartialEq + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>> Mat2x2<T> {
+
+    /// determinant.
     pub fn determinant(self) -> T {
         // xx  yx
         // xy  yy
@@ -52,7 +151,8 @@ impl<T: Zero + Add<T,Output=T> + Mul<T,Output=T> + Div<T,Output=T> + Neg<Output=
         xx * axx + xy * axy
     }
 
-    pub fn inverse(self) -> Self {
+    /// cofactor matrix, where each element is replaced by its cofactor.
+    pub fn cofactor_matrix(self) -> Self {
         // xx  yx
         // xy  yy
         let xx = self.x.x;
@@ -60,25 +160,62 @@ impl<T: Zero + Add<T,Output=T> + Mul<T,Output=T> + Div<T,Output=T> + Neg<Output=
         let yx = self.y.x;
         let yy = self.y.y;
 
-        // adjoint of first column
+        // cofactors
         let axx = yy;
         let axy = -yx;
-
-        // determinant
-        let det = xx * axx + xy * axy;
-        if det == T::ZERO {
-            return self;
-        }
-
-        // rest of adjoint
         let ayx = -xy;
         let ayy = xx;
 
-        // transpose of adjoint divided by determinant
         Mat2x2 {
             x: Vec2 { x: axx,y: axy, },
             y: Vec2 { x: ayx,y: ayy, },
-        } / det
+        }
+    }
+
+    /// adjugate (transpose of the cofactor matrix), which is the inverse times the determinant, even for singular
+    /// matrices.
+    pub fn adjugate(self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// inverse, or `None` if the matrix cannot be inverted.
+    pub fn try_inverse(self) -> Option<Self> {
+        let a = self.cofactor_matrix();
+
+        // determinant
+        let det = self.x.x * a.x.x + self.x.y * a.x.y;
+        if det == T::ZERO {
+            return None;
+        }
+
+        // transpose of cofactors divided by determinant
+        Some(a.transpose() / det)
+    }
+
+    /// inverse, or the matrix itself if it cannot be inverted.
+    ///
+    /// Prefer [`Mat2x2::try_inverse`] or [`Mat2x2::inverse_checked`] when the matrix might be singular.
+    pub fn inverse(self) -> Self {
+        self.try_inverse().unwrap_or(self)
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix cannot be inverted.
+    pub fn inverse_checked(self) -> std::result::Result<Self,SingularMatrixError> {
+        self.try_inverse().ok_or(SingularMatrixError)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialEq + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat2x2<T> {
+
+    /// condition number estimate in the 1-norm, see [`Matrix::condition_number`].
+    pub fn condition_number(self) -> T {
+        Matrix::from(self).condition_number()
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix is singular or too close to singular, see
+    /// [`Matrix::inverse_with_tolerance`].
+    pub fn inverse_with_tolerance(self,tolerance: T) -> std::result::Result<Self,SingularMatrixError> {
+        Matrix::from(self).inverse_with_tolerance(tolerance).map(Mat2x2::from)
     }
 }
 
@@ -124,14 +261,45 @@ impl<T> PartialEq for Mat2x2<T> where Vec2<T>: PartialEq {
     }
 }
 
-impl<T: Display> Display for Mat2x2<T> where Vec2<T>: Display {
+impl<T> Display for Mat2x2<T> where Vec2<T>: Display {
     fn fmt(&self,f: &mut Formatter) -> Result {
         write!(f,"[{},{}]",self.x,self.y)
     }
 }
 
+impl<T> Index<(usize,usize)> for Mat2x2<T> {
+    type Output = T;
+    fn index(&self,(row,col): (usize,usize)) -> &T {
+        let column = match col {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &column.x,
+            1 => &column.y,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize,usize)> for Mat2x2<T> {
+    fn index_mut(&mut self,(row,col): (usize,usize)) -> &mut T {
+        let column = match col {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &mut column.x,
+            1 => &mut column.y,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
 // matrix + matrix
-impl<T: Add<T,Output=T>> Add<Mat2x2<T>> for Mat2x2<T> where Vec2<T>: Add<Vec2<T>,Output=Vec2<T>> {
+impl<T> Add<Mat2x2<T>> for Mat2x2<T> where Vec2<T>: Add<Vec2<T>,Output=Vec2<T>> {
     type Output = Self;
     fn add(self,other: Self) -> Self {
         Mat2x2 {
@@ -142,7 +310,7 @@ impl<T: Add<T,Output=T>> Add<Mat2x2<T>> for Mat2x2<T> where Vec2<T>: Add<Vec2<T>
 }
 
 // matrix - matrix
-impl<T: Sub<T,Output=T>> Sub<Mat2x2<T>> for Mat2x2<T> where Vec2<T>: Sub<Vec2<T>,Output=Vec2<T>> {
+impl<T> Sub<Mat2x2<T>> for Mat2x2<T> where Vec2<T>: Sub<Vec2<T>,Output=Vec2<T>> {
     type Output = Self;
     fn sub(self,other: Self) -> Self {
         Mat2x2 {
@@ -169,10 +337,10 @@ macro_rules! scalar_mat2x2_mul {
     }
 }
 
-scalar_mat2x2_mul!(f32 f64);
+scalar_mat2x2_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
 
 // matrix * scalar
-impl<T> Mul<T> for Mat2x2<T> where Vec2<T>: Mul<T,Output=Vec2<T>> {
+impl<T: Copy> Mul<T> for Mat2x2<T> where Vec2<T>: Mul<T,Output=Vec2<T>> {
     type Output = Self;
     fn mul(self,other: T) -> Self {
         Mat2x2 {
@@ -187,8 +355,8 @@ impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec2<T>> for Mat2x2<T> {
     type Output = Vec2<T>;
     fn mul(self,other: Vec2<T>) -> Vec2<T> {
         Vec2 {
-            x: self.x.x * other.x + self.x.y * other.y,
-            y: self.y.x * other.x + self.y.y * other.y,
+            x: self.x.x * other.x + self.y.x * other.y,
+            y: self.x.y * other.x + self.y.y * other.y,
         }
     }
 }
@@ -199,12 +367,12 @@ impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat2x2<T>> for Mat2x2<T> {
     fn mul(self,other: Mat2x2<T>) -> Mat2x2<T> {
         Mat2x2 {
             x: Vec2 {
-                x: self.x.x * other.x.x + self.x.y * other.y.x,
-                y: self.x.x * other.x.y + self.x.y * other.y.y,
+                x: self.x.x * other.x.x + self.y.x * other.x.y,
+                y: self.x.y * other.x.x + self.y.y * other.x.y,
             },
             y: Vec2 {
-                x: self.y.x * other.x.x + self.y.y * other.y.x,
-                y: self.y.x * other.x.y + self.y.y * other.y.y,
+                x: self.x.x * other.y.x + self.y.x * other.y.y,
+                y: self.x.y * other.y.x + self.y.y * other.y.y,
             },
         }
     }
@@ -250,11 +418,11 @@ impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat2x2<T> {
 // matrix *= matrix
 impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat2x2<T>> for Mat2x2<T> {
     fn mul_assign(&mut self,other: Mat2x2<T>) {
-        let xx = self.x.x * other.x.x + self.x.y * other.y.x;
-        let xy = self.x.x * other.x.y + self.x.y * other.y.y;
+        let xx = self.x.x * other.x.x + self.y.x * other.x.y;
+        let xy = self.x.y * other.x.x + self.y.y * other.x.y;
+        let yx = self.x.x * other.y.x + self.y.x * other.y.y;
+        let yy = self.x.y * other.y.x + self.y.y * other.y.y;
         self.x = Vec2 { x: xx,y: xy, };
-        let yx = self.y.x * other.x.x + self.y.y * other.y.x;
-        let yy = self.y.x * other.x.y + self.y.y * other.y.y;
         self.y = Vec2 { x: yx,y: yy, };
     }
 }
@@ -279,4 +447,69 @@ impl<T: Neg<Output=T>> Neg for Mat2x2<T> {
         }
     }
 }
-*/
+
+#[allow(non_camel_case_types)]
+pub type f32m2 = Mat2x2<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m2 = Mat2x2<f64>;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    #[test]
+    fn transpose() {
+        let m = Mat2x2::from([1.0,2.0,3.0,4.0]);
+        assert!(m.transpose() == Mat2x2::from([1.0,3.0,2.0,4.0]));
+        assert!(m.transpose().transpose() == m);
+    }
+
+    #[test]
+    fn determinant() {
+        let m = Mat2x2::from([1.0,2.0,3.0,4.0]);
+        assert_eq!(m.determinant(),-2.0);
+        assert_eq!(Mat2x2::<f64>::IDENTITY.determinant(),1.0);
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Mat2x2::from([1.0,2.0,3.0,4.0]);
+        assert!(m.inverse() == Mat2x2::from([-2.0,1.0,1.5,-0.5]));
+        assert!(m * m.inverse() == Mat2x2::IDENTITY);
+    }
+
+    #[test]
+    fn singular_inverse() {
+        let m = Mat2x2::from([1.0,2.0,2.0,4.0]);
+        assert!(m.try_inverse().is_none());
+        assert_eq!(m.inverse_checked(),Err(SingularMatrixError));
+        assert!(m.inverse() == m);
+    }
+
+    #[test]
+    fn products() {
+        let a = Mat2x2::from([1.0,2.0,3.0,4.0]);
+        let b = Mat2x2::from([5.0,6.0,7.0,8.0]);
+        assert!(a * b == Mat2x2::from([23.0,34.0,31.0,46.0]));
+        assert!(a * Vec2 { x: 1.0,y: -1.0, } == Vec2 { x: -2.0,y: -2.0, });
+    }
+
+    #[test]
+    fn from_angle() {
+        let r = Mat2x2::from_angle(std::f64::consts::FRAC_PI_2);
+        assert!(close(r,Mat2x2::from([0.0,1.0,-1.0,0.0])));
+        let v = r * Vec2 { x: 1.0,y: 0.0, };
+        assert!((v.x).abs() < 1e-12 && (v.y - 1.0).abs() < 1e-12);
+        assert!(close(r * r.transpose(),Mat2x2::IDENTITY));
+    }
+
+    #[test]
+    fn from_diagonal() {
+        let s = Mat2x2::from_diagonal(Vec2 { x: 2.0,y: -3.0, });
+        assert!(s * Vec2 { x: 1.0,y: 1.0, } == Vec2 { x: 2.0,y: -3.0, });
+        assert_eq!(s.determinant(),-6.0);
+    }
+}
@@ -0,0 +1,309 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// 2x3 matrix template.
+///
+/// Following the GLSL convention, a `MatCxR` has C columns and R rows. This one is stored as two column vectors
+/// `x` and `y` of [`Vec3`], so it maps a [`Vec2`] to a [`Vec3`] by `m * v`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat2x3<T> {
+    pub x: Vec3<T>,
+    pub y: Vec3<T>,
+}
+
+impl<T: Zero> Mat2x3<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat2x3 {
+        x: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        y: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+    };
+}
+
+impl<T: Copy> Mat2x3<T> {
+
+    /// transpose.
+    pub fn transpose(self) -> Mat3x2<T> {
+        Mat3x2 {
+            x: Vec2 { x: self.x.x,y: self.y.x, },
+            y: Vec2 { x: self.x.y,y: self.y.y, },
+            z: Vec2 { x: self.x.z,y: self.y.z, },
+        }
+    }
+}
+
+impl<T: Copy> From<[Vec3<T>; 2]> for Mat2x3<T> {
+    fn from(array: [Vec3<T>; 2]) -> Self {
+        Mat2x3 {
+            x: array[0],
+            y: array[1],
+        }
+    }
+}
+
+impl<T: Copy> From<&[Vec3<T>; 2]> for Mat2x3<T> {
+    fn from(slice: &[Vec3<T>; 2]) -> Self {
+        Mat2x3 {
+            x: slice[0],
+            y: slice[1],
+        }
+    }
+}
+
+impl<T: Copy> From<[T; 6]> for Mat2x3<T> {
+    fn from(array: [T; 6]) -> Self {
+        Mat2x3 {
+            x: Vec3 { x: array[0],y: array[1],z: array[2], },
+            y: Vec3 { x: array[3],y: array[4],z: array[5], },
+        }
+    }
+}
+
+impl<T: Copy> From<&[T; 6]> for Mat2x3<T> {
+    fn from(slice: &[T; 6]) -> Self {
+        Mat2x3 {
+            x: Vec3 { x: slice[0],y: slice[1],z: slice[2], },
+            y: Vec3 { x: slice[3],y: slice[4],z: slice[5], },
+        }
+    }
+}
+
+impl<T> PartialEq for Mat2x3<T> where Vec3<T>: PartialEq {
+    fn eq(&self,other: &Self) -> bool {
+        (self.x == other.x) && (self.y == other.y)
+    }
+}
+
+impl<T> Display for Mat2x3<T> where Vec3<T>: Display {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"[{},{}]",self.x,self.y)
+    }
+}
+
+// matrix + matrix
+impl<T> Add<Mat2x3<T>> for Mat2x3<T> where Vec3<T>: Add<Vec3<T>,Output=Vec3<T>> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self {
+        Mat2x3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// matrix += matrix
+impl<T> AddAssign<Mat2x3<T>> for Mat2x3<T> where Vec3<T>: AddAssign<Vec3<T>> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// matrix - matrix
+impl<T> Sub<Mat2x3<T>> for Mat2x3<T> where Vec3<T>: Sub<Vec3<T>,Output=Vec3<T>> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self {
+        Mat2x3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+// matrix -= matrix
+impl<T> SubAssign<Mat2x3<T>> for Mat2x3<T> where Vec3<T>: SubAssign<Vec3<T>> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+// scalar * matrix
+macro_rules! scalar_mat2x3_mul {
+    ($($t:ty)+) => {
+        $(
+            impl Mul<Mat2x3<$t>> for $t {
+                type Output = Mat2x3<$t>;
+                fn mul(self,other: Mat2x3<$t>) -> Mat2x3<$t> {
+                    Mat2x3 {
+                        x: self * other.x,
+                        y: self * other.y,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+scalar_mat2x3_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
+
+// matrix * scalar
+impl<T: Copy> Mul<T> for Mat2x3<T> where Vec3<T>: Mul<T,Output=Vec3<T>> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self {
+        Mat2x3 {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+// matrix * vector
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec2<T>> for Mat2x3<T> {
+    type Output = Vec3<T>;
+    fn mul(self,other: Vec2<T>) -> Vec3<T> {
+        Vec3 {
+            x: self.x.x * other.x + self.y.x * other.y,
+            y: self.x.y * other.x + self.y.y * other.y,
+            z: self.x.z * other.x + self.y.z * other.y,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat2x2<T>> for Mat2x3<T> {
+    type Output = Mat2x3<T>;
+    fn mul(self,other: Mat2x2<T>) -> Mat2x3<T> {
+        Mat2x3 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x2<T>> for Mat2x3<T> {
+    type Output = Mat3x3<T>;
+    fn mul(self,other: Mat3x2<T>) -> Mat3x3<T> {
+        Mat3x3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat2x3<T>> for Mat3x3<T> {
+    type Output = Mat2x3<T>;
+    fn mul(self,other: Mat2x3<T>) -> Mat2x3<T> {
+        Mat2x3 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+// matrix *= scalar
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat2x3<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x.x *= other;
+        self.x.y *= other;
+        self.x.z *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.y.z *= other;
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat2x2<T>> for Mat2x3<T> {
+    fn mul_assign(&mut self,other: Mat2x2<T>) {
+        *self = *self * other;
+    }
+}
+
+// matrix / scalar
+impl<T: Copy + Div<T,Output=T>> Div<T> for Mat2x3<T> {
+    type Output = Mat2x3<T>;
+    fn div(self,other: T) -> Mat2x3<T> {
+        Mat2x3 {
+            x: Vec3 { x: self.x.x / other,y: self.x.y / other,z: self.x.z / other, },
+            y: Vec3 { x: self.y.x / other,y: self.y.y / other,z: self.y.z / other, },
+        }
+    }
+}
+
+// matrix /= scalar
+impl<T: Copy + DivAssign<T>> DivAssign<T> for Mat2x3<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x.x /= other;
+        self.x.y /= other;
+        self.x.z /= other;
+        self.y.x /= other;
+        self.y.y /= other;
+        self.y.z /= other;
+    }
+}
+
+// -matrix
+impl<T: Neg<Output=T>> Neg for Mat2x3<T> {
+    type Output = Mat2x3<T>;
+    fn neg(self) -> Mat2x3<T> {
+        Mat2x3 {
+            x: Vec3 { x: -self.x.x,y: -self.x.y,z: -self.x.z, },
+            y: Vec3 { x: -self.y.x,y: -self.y.y,z: -self.y.z, },
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32m2x3 = Mat2x3<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m2x3 = Mat2x3<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat2x3(m: Mat2x3<f64>) -> Matrix<f64,3,2> {
+        Matrix([[m.x.x,m.x.y,m.x.z],[m.y.x,m.y.y,m.y.z]])
+    }
+
+    fn mat3x2(m: Mat3x2<f64>) -> Matrix<f64,2,3> {
+        Matrix([[m.x.x,m.x.y],[m.y.x,m.y.y],[m.z.x,m.z.y]])
+    }
+
+    #[test]
+    fn products_match_matrix() {
+        let a = Mat2x3::from([2.0,-4.0,1.0,-5.0,0.0,5.0]);
+        let b = Vec2 { x: -2.0,y: 3.0, };
+        assert!(mat2x3(a) * VecN::from(b) == VecN::from(a * b));
+        let a = Mat2x3::from([5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        let b = Mat2x2::from([1.0,-5.0,0.0,5.0]);
+        assert!(mat2x3(a) * Matrix::from(b) == mat2x3(a * b));
+        let a = Mat2x3::from([-3.0,2.0,-4.0,1.0,-5.0,0.0]);
+        let b = Mat3x2::from([4.0,-2.0,3.0,-3.0,2.0,-4.0]);
+        assert!(mat2x3(a) * mat3x2(b) == Matrix::from(a * b));
+        let a = Mat3x3::from([0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0]);
+        let b = Mat2x3::from([-4.0,1.0,-5.0,0.0,5.0,-1.0]);
+        assert!(Matrix::from(a) * mat2x3(b) == mat2x3(a * b));
+        let mut a = Mat2x3::from([3.0,-3.0,2.0,-4.0,1.0,-5.0]);
+        let b = Mat2x2::from([-1.0,4.0,-2.0,3.0]);
+        let expected = mat2x3(a) * Matrix::from(b);
+        a *= b;
+        assert!(mat2x3(a) == expected);
+    }
+}
@@ -0,0 +1,322 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// 3x2 matrix template.
+///
+/// Following the GLSL convention, a `MatCxR` has C columns and R rows. This one is stored as three column vectors
+/// `x`, `y` and `z` of [`Vec2`], so it maps a [`Vec3`] to a [`Vec2`] by `m * v`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat3x2<T> {
+    pub x: Vec2<T>,
+    pub y: Vec2<T>,
+    pub z: Vec2<T>,
+}
+
+impl<T: Zero> Mat3x2<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat3x2 {
+        x: Vec2 { x: T::ZERO,y: T::ZERO, },
+        y: Vec2 { x: T::ZERO,y: T::ZERO, },
+        z: Vec2 { x: T::ZERO,y: T::ZERO, },
+    };
+}
+
+impl<T: Copy> Mat3x2<T> {
+
+    /// transpose.
+    pub fn transpose(self) -> Mat2x3<T> {
+        Mat2x3 {
+            x: Vec3 { x: self.x.x,y: self.y.x,z: self.z.x, },
+            y: Vec3 { x: self.x.y,y: self.y.y,z: self.z.y, },
+        }
+    }
+}
+
+impl<T: Copy> From<[Vec2<T>; 3]> for Mat3x2<T> {
+    fn from(array: [Vec2<T>; 3]) -> Self {
+        Mat3x2 {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+        }
+    }
+}
+
+impl<T: Copy> From<&[Vec2<T>; 3]> for Mat3x2<T> {
+    fn from(slice: &[Vec2<T>; 3]) -> Self {
+        Mat3x2 {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+        }
+    }
+}
+
+impl<T: Copy> From<[T; 6]> for Mat3x2<T> {
+    fn from(array: [T; 6]) -> Self {
+        Mat3x2 {
+            x: Vec2 { x: array[0],y: array[1], },
+            y: Vec2 { x: array[2],y: array[3], },
+            z: Vec2 { x: array[4],y: array[5], },
+        }
+    }
+}
+
+impl<T: Copy> From<&[T; 6]> for Mat3x2<T> {
+    fn from(slice: &[T; 6]) -> Self {
+        Mat3x2 {
+            x: Vec2 { x: slice[0],y: slice[1], },
+            y: Vec2 { x: slice[2],y: slice[3], },
+            z: Vec2 { x: slice[4],y: slice[5], },
+        }
+    }
+}
+
+impl<T> PartialEq for Mat3x2<T> where Vec2<T>: PartialEq {
+    fn eq(&self,other: &Self) -> bool {
+        (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
+    }
+}
+
+impl<T> Display for Mat3x2<T> where Vec2<T>: Display {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"[{},{},{}]",self.x,self.y,self.z)
+    }
+}
+
+// matrix + matrix
+impl<T> Add<Mat3x2<T>> for Mat3x2<T> where Vec2<T>: Add<Vec2<T>,Output=Vec2<T>> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self {
+        Mat3x2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+// matrix += matrix
+impl<T> AddAssign<Mat3x2<T>> for Mat3x2<T> where Vec2<T>: AddAssign<Vec2<T>> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+// matrix - matrix
+impl<T> Sub<Mat3x2<T>> for Mat3x2<T> where Vec2<T>: Sub<Vec2<T>,Output=Vec2<T>> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self {
+        Mat3x2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+// matrix -= matrix
+impl<T> SubAssign<Mat3x2<T>> for Mat3x2<T> where Vec2<T>: SubAssign<Vec2<T>> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+// scalar * matrix
+macro_rules! scalar_mat3x2_mul {
+    ($($t:ty)+) => {
+        $(
+            impl Mul<Mat3x2<$t>> for $t {
+                type Output = Mat3x2<$t>;
+                fn mul(self,other: Mat3x2<$t>) -> Mat3x2<$t> {
+                    Mat3x2 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+scalar_mat3x2_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
+
+// matrix * scalar
+impl<T: Copy> Mul<T> for Mat3x2<T> where Vec2<T>: Mul<T,Output=Vec2<T>> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self {
+        Mat3x2 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+// matrix * vector
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec3<T>> for Mat3x2<T> {
+    type Output = Vec2<T>;
+    fn mul(self,other: Vec3<T>) -> Vec2<T> {
+        Vec2 {
+            x: self.x.x * other.x + self.y.x * other.y + self.z.x * other.z,
+            y: self.x.y * other.x + self.y.y * other.y + self.z.y * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat2x3<T>> for Mat3x2<T> {
+    type Output = Mat2x2<T>;
+    fn mul(self,other: Mat2x3<T>) -> Mat2x2<T> {
+        Mat2x2 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x3<T>> for Mat3x2<T> {
+    type Output = Mat3x2<T>;
+    fn mul(self,other: Mat3x3<T>) -> Mat3x2<T> {
+        Mat3x2 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x2<T>> for Mat2x2<T> {
+    type Output = Mat3x2<T>;
+    fn mul(self,other: Mat3x2<T>) -> Mat3x2<T> {
+        Mat3x2 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix *= scalar
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat3x2<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x.x *= other;
+        self.x.y *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.z.x *= other;
+        self.z.y *= other;
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat3x3<T>> for Mat3x2<T> {
+    fn mul_assign(&mut self,other: Mat3x3<T>) {
+        *self = *self * other;
+    }
+}
+
+// matrix / scalar
+impl<T: Copy + Div<T,Output=T>> Div<T> for Mat3x2<T> {
+    type Output = Mat3x2<T>;
+    fn div(self,other: T) -> Mat3x2<T> {
+        Mat3x2 {
+            x: Vec2 { x: self.x.x / other,y: self.x.y / other, },
+            y: Vec2 { x: self.y.x / other,y: self.y.y / other, },
+            z: Vec2 { x: self.z.x / other,y: self.z.y / other, },
+        }
+    }
+}
+
+// matrix /= scalar
+impl<T: Copy + DivAssign<T>> DivAssign<T> for Mat3x2<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x.x /= other;
+        self.x.y /= other;
+        self.y.x /= other;
+        self.y.y /= other;
+        self.z.x /= other;
+        self.z.y /= other;
+    }
+}
+
+// -matrix
+impl<T: Neg<Output=T>> Neg for Mat3x2<T> {
+    type Output = Mat3x2<T>;
+    fn neg(self) -> Mat3x2<T> {
+        Mat3x2 {
+            x: Vec2 { x: -self.x.x,y: -self.x.y, },
+            y: Vec2 { x: -self.y.x,y: -self.y.y, },
+            z: Vec2 { x: -self.z.x,y: -self.z.y, },
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32m3x2 = Mat3x2<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m3x2 = Mat3x2<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat2x3(m: Mat2x3<f64>) -> Matrix<f64,3,2> {
+        Matrix([[m.x.x,m.x.y,m.x.z],[m.y.x,m.y.y,m.y.z]])
+    }
+
+    fn mat3x2(m: Mat3x2<f64>) -> Matrix<f64,2,3> {
+        Matrix([[m.x.x,m.x.y],[m.y.x,m.y.y],[m.z.x,m.z.y]])
+    }
+
+    #[test]
+    fn products_match_matrix() {
+        let a = Mat3x2::from([2.0,-4.0,1.0,-5.0,0.0,5.0]);
+        let b = Vec3 { x: -2.0,y: 3.0,z: -3.0, };
+        assert!(mat3x2(a) * VecN::from(b) == VecN::from(a * b));
+        let a = Mat3x2::from([5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        let b = Mat2x3::from([1.0,-5.0,0.0,5.0,-1.0,4.0]);
+        assert!(mat3x2(a) * mat2x3(b) == Matrix::from(a * b));
+        let a = Mat3x2::from([-3.0,2.0,-4.0,1.0,-5.0,0.0]);
+        let b = Mat3x3::from([4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0]);
+        assert!(mat3x2(a) * Matrix::from(b) == mat3x2(a * b));
+        let a = Mat2x2::from([0.0,5.0,-1.0,4.0]);
+        let b = Mat3x2::from([-4.0,1.0,-5.0,0.0,5.0,-1.0]);
+        assert!(Matrix::from(a) * mat3x2(b) == mat3x2(a * b));
+        let mut a = Mat3x2::from([3.0,-3.0,2.0,-4.0,1.0,-5.0]);
+        let b = Mat3x3::from([-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0]);
+        let expected = mat3x2(a) * Matrix::from(b);
+        a *= b;
+        assert!(mat3x2(a) == expected);
+    }
+}
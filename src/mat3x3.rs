@@ -17,10 +17,18 @@ use {
             MulAssign,
             DivAssign,
             Neg,
+            Index,
+            IndexMut,
         },
     },
 };
 
+/// 3x3 matrix template.
+///
+/// The matrix is stored as three column vectors `x`, `y` and `z`, so `m.y.x` is the element in the first row of the
+/// second column. A matrix transforms a vector by `m * v`, and `a * b` applies `b` first.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
 pub struct Mat3x3<T> {
     pub x: Vec3<T>,
@@ -28,8 +36,26 @@ pub struct Mat3x3<T> {
     pub z: Vec3<T>,
 }
 
-/*
-impl<T: Zero + PartialEq + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> + Div<T,Output=T> + Neg<Output=T>> Mat3x3<T> {
+impl<T: Zero + One> Mat3x3<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat3x3 {
+        x: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        y: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+    };
+
+    /// identity matrix.
+    pub const IDENTITY: Self = Mat3x3 {
+        x: Vec3 { x: T::ONE,y: T::ZERO,z: T::ZERO, },
+        y: Vec3 { x: T::ZERO,y: T::ONE,z: T::ZERO, },
+        z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ONE, },
+    };
+}
+
+impl<T: Copy> Mat3x3<T> {
+
+    /// transpose.
     pub fn transpose(self) -> Mat3x3<T> {
         Mat3x3 {
             x: Vec3 { x: self.x.x,y: self.y.x,z: self.z.x, },
@@ -38,8 +64,126 @@ impl<T: Zero + PartialEq + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         }
     }
 
-    pub fn determinant(self) -> T {
+    /// build a matrix from `f(row,col)` for each element.
+    pub fn from_fn<F: FnMut(usize,usize) -> T>(mut f: F) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: f(0,0),y: f(1,0),z: f(2,0), },
+            y: Vec3 { x: f(0,1),y: f(1,1),z: f(2,1), },
+            z: Vec3 { x: f(0,2),y: f(1,2),z: f(2,2), },
+        }
+    }
+
+    /// apply `f` to each element.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Mat3x3<U> {
+        Mat3x3 { x: self.x.map(&mut f),y: self.y.map(&mut f),z: self.z.map(&mut f), }
+    }
+
+    /// row `i`.
+    pub fn row(self,i: usize) -> Vec3<T> {
+        Vec3 { x: self[(i,0)],y: self[(i,1)],z: self[(i,2)], }
+    }
+
+    /// column `j`.
+    pub fn col(self,j: usize) -> Vec3<T> {
+        Vec3 { x: self[(0,j)],y: self[(1,j)],z: self[(2,j)], }
+    }
+
+    /// replace row `i`.
+    pub fn set_row(&mut self,i: usize,row: Vec3<T>) {
+        self[(i,0)] = row.x;
+        self[(i,1)] = row.y;
+        self[(i,2)] = row.z;
+    }
+
+    /// replace column `j`.
+    pub fn set_col(&mut self,j: usize,col: Vec3<T>) {
+        self[(0,j)] = col.x;
+        self[(1,j)] = col.y;
+        self[(2,j)] = col.z;
+    }
+
+    /// main diagonal.
+    pub fn diagonal(self) -> Vec3<T> {
+        Vec3 { x: self.x.x,y: self.y.y,z: self.z.z, }
+    }
+
+    /// iterate over the elements, column by column.
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        IntoIterator::into_iter([
+            &self.x.x,&self.x.y,&self.x.z,
+            &self.y.x,&self.y.y,&self.y.z,
+            &self.z.x,&self.z.y,&self.z.z,
+        ])
+    }
+}
+
+impl<T: Copy + Zero> Mat3x3<T> {
+
+    /// diagonal matrix with `diagonal` on the main diagonal.
+    pub fn from_diagonal(diagonal: Vec3<T>) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: diagonal.x,y: T::ZERO,z: T::ZERO, },
+            y: Vec3 { x: T::ZERO,y: diagonal.y,z: T::ZERO, },
+            z: Vec3 { x: T::ZERO,y: T::ZERO,z: diagonal.z, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + One> Mat3x3<T> {
 
+    /// 2D translation matrix.
+    pub fn from_translation(translation: Vec2<T>) -> Self {
+        let mut m = Self::IDENTITY;
+        m.z = Vec3 { x: translation.x,y: translation.y,z: T::ONE, };
+        m
+    }
+
+    /// 2D scale matrix.
+    pub fn from_scale(scale: Vec2<T>) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: scale.x,y: T::ZERO,z: T::ZERO, },
+            y: Vec3 { x: T::ZERO,y: scale.y,z: T::ZERO, },
+            z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ONE, },
+        }
+    }
+
+    /// 2D shear matrix, where `xy` is how far x moves for each unit of y, and `yx` how far y moves for each unit of x.
+    pub fn from_shear(xy: T,yx: T) -> Self {
+        Mat3x3 {
+            x: Vec3 { x: T::ONE,y: yx,z: T::ZERO, },
+            y: Vec3 { x: xy,y: T::ONE,z: T::ZERO, },
+            z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ONE, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + Mul<Output=T> + Neg<Output=T> + Real> Mat3x3<T> {
+
+    /// 2D counter-clockwise rotation matrix, `angle` in radians.
+    pub fn from_angle(angle: T) -> Self {
+        let (s,c) = angle.sin_cos();
+        Mat3x3 {
+            x: Vec3 { x: c,y: s,z: T::ZERO, },
+            y: Vec3 { x: -s,y: c,z: T::ZERO, },
+            z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ONE, },
+        }
+    }
+
+    /// 2D scale, then rotation, then translation.
+    pub fn from_trs(translation: Vec2<T>,angle: T,scale: Vec2<T>) -> Self {
+        let (s,c) = angle.sin_cos();
+        Mat3x3 {
+            x: Vec3 { x: c * scale.x,y: s * scale.x,z: T::ZERO, },
+            y: Vec3 { x: -s * scale.y,y: c * scale.y,z: T::ZERO, },
+            z: Vec3 { x: translation.x,y: translation.y,z: T::ONE, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + PartialEq + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>> Mat3x3<T> {
+
+    /// determinant.
+    pub fn determinant(self) -> T {
         // xx  yx  zx
         // xy  yy  zy
         // xz  yz  zz
@@ -53,25 +197,17 @@ impl<T: Zero + PartialEq + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         let zy = self.z.y;
         let zz = self.z.z;
 
-        // adjoint of first column
-
-        // yy  zy
-        // yz  zz
-        let axx = yy * zz - zy * yz;
-        
-        // yz  zz
-        // yx  zx
-        let axy = -(yz * zx - zz * yx);
-        
-        // yx  zx
-        // yy  zy
-        let axz = yx * zy - zx * yy;
-
-        // dot
+        // cofactors of the first column (y × z)
+        let axx = yy * zz - yz * zy;
+        let axy = yz * zx - yx * zz;
+        let axz = yx * zy - yy * zx;
+
+        // determinant
         xx * axx + xy * axy + xz * axz
     }
 
-    pub fn inverse(self) -> Self {
+    /// cofactor matrix, where each element is replaced by its cofactor.
+    pub fn cofactor_matrix(self) -> Self {
         // xx  yx  zx
         // xy  yy  zy
         // xz  yz  zz
@@ -85,31 +221,90 @@ impl<T: Zero + PartialEq + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         let zy = self.z.y;
         let zz = self.z.z;
 
-        // adjoint of first column
-        let axx = yy * zz - zy * yz;
-        let axy = -(yz * zx - zz * yx);
-        let axz = yx * zy - zx * yy;
+        // cofactors (y × z, z × x and x × y)
+        let axx = yy * zz - yz * zy;
+        let axy = yz * zx - yx * zz;
+        let axz = yx * zy - yy * zx;
+        let ayx = zy * xz - zz * xy;
+        let ayy = zz * xx - zx * xz;
+        let ayz = zx * xy - zy * xx;
+        let azx = xy * yz - xz * yy;
+        let azy = xz * yx - xx * yz;
+        let azz = xx * yy - xy * yx;
 
-        // determinant
-        let det = xx * axx + xy * axy + xz * axz;
-        if det == T::ZERO {
-            return self;
-        }
-
-        // rest of adjoint
-        let ayx = -(zy * xz - xy * zz);
-        let ayy = zz * xx - xz * zx;
-        let ayz = -(zx * xy - xx * zy);
-        let azx = xy * yz - yy * xz;
-        let azy = -(xz * yx - yz * xx);
-        let azz = xx * yy - yx * xy;
-        
-        // transpose of adjoint divided by determinant
         Mat3x3 {
             x: Vec3 { x: axx,y: axy,z: axz, },
             y: Vec3 { x: ayx,y: ayy,z: ayz, },
             z: Vec3 { x: azx,y: azy,z: azz, },
-        } / det
+        }
+    }
+
+    /// adjugate (transpose of the cofactor matrix), which is the inverse times the determinant, even for singular
+    /// matrices.
+    pub fn adjugate(self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// inverse, or `None` if the matrix cannot be inverted.
+    pub fn try_inverse(self) -> Option<Self> {
+        let a = self.cofactor_matrix();
+
+        // determinant
+        let det = self.x.x * a.x.x + self.x.y * a.x.y + self.x.z * a.x.z;
+        if det == T::ZERO {
+            return None;
+        }
+
+        // transpose of cofactors divided by determinant
+        Some(a.transpose() / det)
+    }
+
+    /// inverse, or the matrix itself if it cannot be inverted.
+    ///
+    /// Prefer [`Mat3x3::try_inverse`] or [`Mat3x3::inverse_checked`] when the matrix might be singular.
+    pub fn inverse(self) -> Self {
+        self.try_inverse().unwrap_or(self)
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix cannot be inverted.
+    pub fn inverse_checked(self) -> std::result::Result<Self,SingularMatrixError> {
+        self.try_inverse().ok_or(SingularMatrixError)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialEq + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat3x3<T> {
+
+    /// condition number estimate in the 1-norm, see [`Matrix::condition_number`].
+    pub fn condition_number(self) -> T {
+        Matrix::from(self).condition_number()
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix is singular or too close to singular, see
+    /// [`Matrix::inverse_with_tolerance`].
+    pub fn inverse_with_tolerance(self,tolerance: T) -> std::result::Result<Self,SingularMatrixError> {
+        Matrix::from(self).inverse_with_tolerance(tolerance).map(Mat3x3::from)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat3x3<T> {
+
+    /// rotation that turns the viewing direction of `handedness` (-Z or +Z) towards `forward`, keeping +Y as close to
+    /// `up` as possible.
+    ///
+    /// When `forward` is parallel to `up`, an arbitrary perpendicular direction is used instead of `up`.
+    pub fn look_rotation(forward: Vec3<T>,up: Vec3<T>,handedness: Handedness) -> Self {
+        let s: T = handedness.forward();
+        let z = forward.normalize() * s;
+        let x = up.cross(z);
+        let length = x.length();
+        let x = if length > T::EPSILON * up.length() {
+            x / length
+        }
+        else {
+            z.any_perpendicular()
+        };
+        let y = z.cross(x);
+        Mat3x3 { x,y,z, }
     }
 }
 
@@ -153,6 +348,27 @@ impl<T: Copy> From<&[T; 9]> for Mat3x3<T> {
     }
 }
 
+impl<T: Copy + Add<Output=T> + Sub<Output=T> + Mul<Output=T>> From<Quaternion<T>> for Mat3x3<T> {
+    /// rotation matrix of a unit quaternion.
+    fn from(q: Quaternion<T>) -> Self {
+        let rr = q.r * q.r;
+        let ri = q.r * q.i;
+        let rj = q.r * q.j;
+        let rk = q.r * q.k;
+        let ii = q.i * q.i;
+        let ij = q.i * q.j;
+        let ik = q.i * q.k;
+        let jj = q.j * q.j;
+        let jk = q.j * q.k;
+        let kk = q.k * q.k;
+        Mat3x3 {
+            x: Vec3 { x: rr + ii - jj - kk,y: (ij + rk) + (ij + rk),z: (ik - rj) + (ik - rj), },
+            y: Vec3 { x: (ij - rk) + (ij - rk),y: rr - ii + jj - kk,z: (jk + ri) + (jk + ri), },
+            z: Vec3 { x: (ik + rj) + (ik + rj),y: (jk - ri) + (jk - ri),z: rr - ii - jj + kk, },
+        }
+    }
+}
+
 impl<T> PartialEq for Mat3x3<T> where Vec3<T>: PartialEq {
     fn eq(&self,other: &Self) -> bool {
         (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
@@ -165,6 +381,41 @@ impl<T> Display for Mat3x3<T> where Vec3<T>: Display {
     }
 }
 
+impl<T> Index<(usize,usize)> for Mat3x3<T> {
+    type Output = T;
+    fn index(&self,(row,col): (usize,usize)) -> &T {
+        let column = match col {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &column.x,
+            1 => &column.y,
+            2 => &column.z,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize,usize)> for Mat3x3<T> {
+    fn index_mut(&mut self,(row,col): (usize,usize)) -> &mut T {
+        let column = match col {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &mut column.x,
+            1 => &mut column.y,
+            2 => &mut column.z,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
 // matrix + matrix
 impl<T> Add<Mat3x3<T>> for Mat3x3<T> where Vec3<T>: Add<Vec3<T>,Output=Vec3<T>> {
     type Output = Self;
@@ -225,10 +476,10 @@ macro_rules! scalar_mat3x3_mul {
     }
 }
 
-scalar_mat3x3_mul!(f32 f64);
+scalar_mat3x3_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
 
 // matrix * scalar
-impl<T> Mul<T> for Mat3x3<T> where Vec3<T>: Mul<T,Output=Vec3<T>> {
+impl<T: Copy> Mul<T> for Mat3x3<T> where Vec3<T>: Mul<T,Output=Vec3<T>> {
     type Output = Self;
     fn mul(self,other: T) -> Self {
         Mat3x3 {
@@ -244,9 +495,9 @@ impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec3<T>> for Mat3x3<T> {
     type Output = Vec3<T>;
     fn mul(self,other: Vec3<T>) -> Vec3<T> {
         Vec3 {
-            x: self.x.x * other.x + self.x.y * other.y + self.x.z * other.z,
-            y: self.y.x * other.x + self.y.y * other.y + self.y.z * other.z,
-            z: self.z.x * other.x + self.z.y * other.y + self.z.z * other.z,
+            x: self.x.x * other.x + self.y.x * other.y + self.z.x * other.z,
+            y: self.x.y * other.x + self.y.y * other.y + self.z.y * other.z,
+            z: self.x.z * other.x + self.y.z * other.y + self.z.z * other.z,
         }
     }
 }
@@ -256,49 +507,32 @@ impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x3<T>> for Mat3x3<T> {
     type Output = Mat3x3<T>;
     fn mul(self,other: Mat3x3<T>) -> Mat3x3<T> {
         Mat3x3 {
-            x: Vec3 {
-                x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x,
-                y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y,
-                z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z,
-            },
-            y: Vec3 {
-                x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x,
-                y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y,
-                z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z,
-            },
-            z: Vec3 {
-                x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x,
-                y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y,
-                z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z,
-            },
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
         }
     }
 }
 
 // matrix *= scalar
-impl<T> MulAssign<T> for Mat3x3<T> where Vec3<T>: MulAssign<T> {
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat3x3<T> {
     fn mul_assign(&mut self,other: T) {
-        self.x *= other;
-        self.y *= other;
-        self.z *= other;
+        self.x.x *= other;
+        self.x.y *= other;
+        self.x.z *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.y.z *= other;
+        self.z.x *= other;
+        self.z.y *= other;
+        self.z.z *= other;
     }
 }
 
 // matrix *= matrix
 impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat3x3<T>> for Mat3x3<T> {
     fn mul_assign(&mut self,other: Mat3x3<T>) {
-        let xx = self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x;
-        let xy = self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y;
-        let xz = self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z;
-        self.x = Vec3 { x: xx,y: xy,z: xz, };
-        let yx = self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x;
-        let yy = self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y;
-        let yz = self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z;
-        self.y = Vec3 { x: yx,y: yy,z: yz, };
-        let zx = self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x;
-        let zy = self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y;
-        let zz = self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z;
-        self.z = Vec3 { x: zx,y: zy,z: zz, }
+        *self = *self * other;
     }
 }
 
@@ -340,4 +574,99 @@ impl<T: Neg<Output=T>> Neg for Mat3x3<T> {
         }
     }
 }
-*/
\ No newline at end of file
+
+#[allow(non_camel_case_types)]
+pub type f32m3 = Mat3x3<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m3 = Mat3x3<f64>;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    #[test]
+    fn known_determinant_and_inverse() {
+        let m = Mat3x3::from([2.0,-1.0,0.0,-1.0,2.0,-1.0,0.0,-1.0,2.0]);
+        assert_eq!(m.determinant(),4.0);
+        let expected = Mat3x3::from([3.0,2.0,1.0,2.0,4.0,2.0,1.0,2.0,3.0]) / 4.0;
+        assert!(close(m.inverse(),expected));
+        assert!(close(m * m.inverse(),Mat3x3::IDENTITY));
+    }
+
+    #[test]
+    fn singular() {
+        let m = Mat3x3::from([1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]);
+        assert_eq!(m.determinant(),0.0);
+        assert!(m.try_inverse().is_none());
+        assert_eq!(m.inverse_checked(),Err(SingularMatrixError));
+        assert!(m.inverse() == m);
+    }
+
+    #[test]
+    fn tolerance() {
+        let tolerance = 1e-10;
+        let good = Mat3x3::from([2.0,-1.0,0.0,-1.0,2.0,-1.0,0.0,-1.0,2.0]);
+        assert!(good.condition_number() < 10.0);
+        assert!(close(good.inverse_with_tolerance(tolerance).unwrap(),good.inverse()));
+        let bad = Mat3x3::from([1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0 + 1e-13]);
+        assert!(bad.try_inverse().is_some());
+        assert!(bad.condition_number() > 1e12);
+        assert_eq!(bad.inverse_with_tolerance(tolerance),Err(SingularMatrixError));
+        assert!(Mat3x3::<f64>::ZERO.condition_number().is_infinite());
+    }
+
+    #[test]
+    fn determinant_matches_lu() {
+        let m = Mat3x3::from([3.0,-1.0,2.0,0.5,4.0,-2.0,1.0,1.5,-3.0]);
+        assert!((m.determinant() - m.lu().determinant()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn from_shear() {
+        let m = Mat3x3::from_shear(0.5,-1.0);
+        assert!(m * Vec3 { x: 2.0,y: 3.0,z: 1.0, } == Vec3 { x: 3.5,y: 1.0,z: 1.0, });
+    }
+
+    #[test]
+    fn from_trs() {
+        let t = Vec2 { x: 1.0,y: -2.0, };
+        let s = Vec2 { x: 2.0,y: 0.5, };
+        let m = Mat3x3::from_trs(t,0.7,s);
+        assert!(close(m,Mat3x3::from_translation(t) * Mat3x3::from_angle(0.7) * Mat3x3::from_scale(s)));
+
+        // quarter turn: the scaled X axis ends up along Y
+        let m = Mat3x3::from_trs(t,std::f64::consts::FRAC_PI_2,s);
+        assert!(close(m * Vec3 { x: 1.0,y: 0.0,z: 1.0, },Vec3 { x: 1.0,y: 0.0,z: 1.0, }));
+        assert!(close(m * Vec3 { x: 0.0,y: 1.0,z: 0.0, },Vec3 { x: -0.5,y: 0.0,z: 0.0, }));
+    }
+
+    fn check_rotation(r: Mat3x3<f64>) {
+        assert!(close(r.transpose() * r,Mat3x3::IDENTITY));
+        assert!((r.determinant() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn look_rotation() {
+        let up = Vec3 { x: 0.0,y: 1.0,z: 0.0, };
+        for (handedness,view) in [(Handedness::Right,-1.0),(Handedness::Left,1.0)] {
+            let forward = Vec3 { x: 1.0,y: -0.5,z: 2.0, };
+            let r = Mat3x3::look_rotation(forward,up,handedness);
+            check_rotation(r);
+            assert!(close(r * Vec3 { x: 0.0,y: 0.0,z: view, },forward.normalize()));
+
+            // +X stays horizontal and +Y leans towards up
+            assert!(r.x.dot(up).abs() < 1e-12);
+            assert!(r.y.dot(up) > 0.0);
+
+            // forward parallel to up still gives a rotation
+            for forward in [up * 3.0,-up] {
+                let r = Mat3x3::look_rotation(forward,up,handedness);
+                check_rotation(r);
+                assert!(close(r * Vec3 { x: 0.0,y: 0.0,z: view, },forward.normalize()));
+            }
+        }
+    }
+}
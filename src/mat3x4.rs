@@ -0,0 +1,340 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// 3x4 matrix template.
+///
+/// Following the GLSL convention, a `MatCxR` has C columns and R rows. This one is stored as three column vectors
+/// `x`, `y` and `z` of [`Vec4`], so it maps a [`Vec3`] to a [`Vec4`] by `m * v`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat3x4<T> {
+    pub x: Vec4<T>,
+    pub y: Vec4<T>,
+    pub z: Vec4<T>,
+}
+
+impl<T: Zero> Mat3x4<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat3x4 {
+        x: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        y: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        z: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+    };
+}
+
+impl<T: Copy> Mat3x4<T> {
+
+    /// transpose.
+    pub fn transpose(self) -> Mat4x3<T> {
+        Mat4x3 {
+            x: Vec3 { x: self.x.x,y: self.y.x,z: self.z.x, },
+            y: Vec3 { x: self.x.y,y: self.y.y,z: self.z.y, },
+            z: Vec3 { x: self.x.z,y: self.y.z,z: self.z.z, },
+            w: Vec3 { x: self.x.w,y: self.y.w,z: self.z.w, },
+        }
+    }
+}
+
+impl<T: Copy> From<[Vec4<T>; 3]> for Mat3x4<T> {
+    fn from(array: [Vec4<T>; 3]) -> Self {
+        Mat3x4 {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+        }
+    }
+}
+
+impl<T: Copy> From<&[Vec4<T>; 3]> for Mat3x4<T> {
+    fn from(slice: &[Vec4<T>; 3]) -> Self {
+        Mat3x4 {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+        }
+    }
+}
+
+impl<T: Copy> From<[T; 12]> for Mat3x4<T> {
+    fn from(array: [T; 12]) -> Self {
+        Mat3x4 {
+            x: Vec4 { x: array[0],y: array[1],z: array[2],w: array[3], },
+            y: Vec4 { x: array[4],y: array[5],z: array[6],w: array[7], },
+            z: Vec4 { x: array[8],y: array[9],z: array[10],w: array[11], },
+        }
+    }
+}
+
+impl<T: Copy> From<&[T; 12]> for Mat3x4<T> {
+    fn from(slice: &[T; 12]) -> Self {
+        Mat3x4 {
+            x: Vec4 { x: slice[0],y: slice[1],z: slice[2],w: slice[3], },
+            y: Vec4 { x: slice[4],y: slice[5],z: slice[6],w: slice[7], },
+            z: Vec4 { x: slice[8],y: slice[9],z: slice[10],w: slice[11], },
+        }
+    }
+}
+
+impl<T> PartialEq for Mat3x4<T> where Vec4<T>: PartialEq {
+    fn eq(&self,other: &Self) -> bool {
+        (self.x == other.x) && (self.y == other.y) && (self.z == other.z)
+    }
+}
+
+impl<T> Display for Mat3x4<T> where Vec4<T>: Display {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"[{},{},{}]",self.x,self.y,self.z)
+    }
+}
+
+// matrix + matrix
+impl<T> Add<Mat3x4<T>> for Mat3x4<T> where Vec4<T>: Add<Vec4<T>,Output=Vec4<T>> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self {
+        Mat3x4 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+// matrix += matrix
+impl<T> AddAssign<Mat3x4<T>> for Mat3x4<T> where Vec4<T>: AddAssign<Vec4<T>> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+// matrix - matrix
+impl<T> Sub<Mat3x4<T>> for Mat3x4<T> where Vec4<T>: Sub<Vec4<T>,Output=Vec4<T>> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self {
+        Mat3x4 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+// matrix -= matrix
+impl<T> SubAssign<Mat3x4<T>> for Mat3x4<T> where Vec4<T>: SubAssign<Vec4<T>> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+// scalar * matrix
+macro_rules! scalar_mat3x4_mul {
+    ($($t:ty)+) => {
+        $(
+            impl Mul<Mat3x4<$t>> for $t {
+                type Output = Mat3x4<$t>;
+                fn mul(self,other: Mat3x4<$t>) -> Mat3x4<$t> {
+                    Mat3x4 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+scalar_mat3x4_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
+
+// matrix * scalar
+impl<T: Copy> Mul<T> for Mat3x4<T> where Vec4<T>: Mul<T,Output=Vec4<T>> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self {
+        Mat3x4 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+// matrix * vector
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec3<T>> for Mat3x4<T> {
+    type Output = Vec4<T>;
+    fn mul(self,other: Vec3<T>) -> Vec4<T> {
+        Vec4 {
+            x: self.x.x * other.x + self.y.x * other.y + self.z.x * other.z,
+            y: self.x.y * other.x + self.y.y * other.y + self.z.y * other.z,
+            z: self.x.z * other.x + self.y.z * other.y + self.z.z * other.z,
+            w: self.x.w * other.x + self.y.w * other.y + self.z.w * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x3<T>> for Mat3x4<T> {
+    type Output = Mat3x4<T>;
+    fn mul(self,other: Mat3x3<T>) -> Mat3x4<T> {
+        Mat3x4 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat4x3<T>> for Mat3x4<T> {
+    type Output = Mat4x4<T>;
+    fn mul(self,other: Mat4x3<T>) -> Mat4x4<T> {
+        Mat4x4 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x4<T>> for Mat4x4<T> {
+    type Output = Mat3x4<T>;
+    fn mul(self,other: Mat3x4<T>) -> Mat3x4<T> {
+        Mat3x4 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix *= scalar
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat3x4<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x.x *= other;
+        self.x.y *= other;
+        self.x.z *= other;
+        self.x.w *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.y.z *= other;
+        self.y.w *= other;
+        self.z.x *= other;
+        self.z.y *= other;
+        self.z.z *= other;
+        self.z.w *= other;
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat3x3<T>> for Mat3x4<T> {
+    fn mul_assign(&mut self,other: Mat3x3<T>) {
+        *self = *self * other;
+    }
+}
+
+// matrix / scalar
+impl<T: Copy + Div<T,Output=T>> Div<T> for Mat3x4<T> {
+    type Output = Mat3x4<T>;
+    fn div(self,other: T) -> Mat3x4<T> {
+        Mat3x4 {
+            x: Vec4 { x: self.x.x / other,y: self.x.y / other,z: self.x.z / other,w: self.x.w / other, },
+            y: Vec4 { x: self.y.x / other,y: self.y.y / other,z: self.y.z / other,w: self.y.w / other, },
+            z: Vec4 { x: self.z.x / other,y: self.z.y / other,z: self.z.z / other,w: self.z.w / other, },
+        }
+    }
+}
+
+// matrix /= scalar
+impl<T: Copy + DivAssign<T>> DivAssign<T> for Mat3x4<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x.x /= other;
+        self.x.y /= other;
+        self.x.z /= other;
+        self.x.w /= other;
+        self.y.x /= other;
+        self.y.y /= other;
+        self.y.z /= other;
+        self.y.w /= other;
+        self.z.x /= other;
+        self.z.y /= other;
+        self.z.z /= other;
+        self.z.w /= other;
+    }
+}
+
+// -matrix
+impl<T: Neg<Output=T>> Neg for Mat3x4<T> {
+    type Output = Mat3x4<T>;
+    fn neg(self) -> Mat3x4<T> {
+        Mat3x4 {
+            x: Vec4 { x: -self.x.x,y: -self.x.y,z: -self.x.z,w: -self.x.w, },
+            y: Vec4 { x: -self.y.x,y: -self.y.y,z: -self.y.z,w: -self.y.w, },
+            z: Vec4 { x: -self.z.x,y: -self.z.y,z: -self.z.z,w: -self.z.w, },
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32m3x4 = Mat3x4<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m3x4 = Mat3x4<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat3x4(m: Mat3x4<f64>) -> Matrix<f64,4,3> {
+        Matrix([[m.x.x,m.x.y,m.x.z,m.x.w],[m.y.x,m.y.y,m.y.z,m.y.w],[m.z.x,m.z.y,m.z.z,m.z.w]])
+    }
+
+    fn mat4x3(m: Mat4x3<f64>) -> Matrix<f64,3,4> {
+        Matrix([[m.x.x,m.x.y,m.x.z],[m.y.x,m.y.y,m.y.z],[m.z.x,m.z.y,m.z.z],[m.w.x,m.w.y,m.w.z]])
+    }
+
+    #[test]
+    fn products_match_matrix() {
+        let a = Mat3x4::from([2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0]);
+        let b = Vec3 { x: -2.0,y: 3.0,z: -3.0, };
+        assert!(mat3x4(a) * VecN::from(b) == VecN::from(a * b));
+        let a = Mat3x4::from([5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0]);
+        let b = Mat3x3::from([1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        assert!(mat3x4(a) * Matrix::from(b) == mat3x4(a * b));
+        let a = Mat3x4::from([-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        let b = Mat4x3::from([4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0]);
+        assert!(mat3x4(a) * mat4x3(b) == Matrix::from(a * b));
+        let a = Mat4x4::from([0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0]);
+        let b = Mat3x4::from([-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0]);
+        assert!(Matrix::from(a) * mat3x4(b) == mat3x4(a * b));
+        let mut a = Mat3x4::from([3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0]);
+        let b = Mat3x3::from([-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0]);
+        let expected = mat3x4(a) * Matrix::from(b);
+        a *= b;
+        assert!(mat3x4(a) == expected);
+    }
+}
@@ -0,0 +1,353 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+        },
+    },
+};
+
+/// 4x3 matrix template.
+///
+/// Following the GLSL convention, a `MatCxR` has C columns and R rows. This one is stored as four column vectors
+/// `x`, `y`, `z` and `w` of [`Vec3`], so it maps a [`Vec4`] to a [`Vec3`] by `m * v`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct Mat4x3<T> {
+    pub x: Vec3<T>,
+    pub y: Vec3<T>,
+    pub z: Vec3<T>,
+    pub w: Vec3<T>,
+}
+
+impl<T: Zero> Mat4x3<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat4x3 {
+        x: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        y: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        z: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+        w: Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, },
+    };
+}
+
+impl<T: Copy> Mat4x3<T> {
+
+    /// transpose.
+    pub fn transpose(self) -> Mat3x4<T> {
+        Mat3x4 {
+            x: Vec4 { x: self.x.x,y: self.y.x,z: self.z.x,w: self.w.x, },
+            y: Vec4 { x: self.x.y,y: self.y.y,z: self.z.y,w: self.w.y, },
+            z: Vec4 { x: self.x.z,y: self.y.z,z: self.z.z,w: self.w.z, },
+        }
+    }
+}
+
+impl<T: Copy> From<[Vec3<T>; 4]> for Mat4x3<T> {
+    fn from(array: [Vec3<T>; 4]) -> Self {
+        Mat4x3 {
+            x: array[0],
+            y: array[1],
+            z: array[2],
+            w: array[3],
+        }
+    }
+}
+
+impl<T: Copy> From<&[Vec3<T>; 4]> for Mat4x3<T> {
+    fn from(slice: &[Vec3<T>; 4]) -> Self {
+        Mat4x3 {
+            x: slice[0],
+            y: slice[1],
+            z: slice[2],
+            w: slice[3],
+        }
+    }
+}
+
+impl<T: Copy> From<[T; 12]> for Mat4x3<T> {
+    fn from(array: [T; 12]) -> Self {
+        Mat4x3 {
+            x: Vec3 { x: array[0],y: array[1],z: array[2], },
+            y: Vec3 { x: array[3],y: array[4],z: array[5], },
+            z: Vec3 { x: array[6],y: array[7],z: array[8], },
+            w: Vec3 { x: array[9],y: array[10],z: array[11], },
+        }
+    }
+}
+
+impl<T: Copy> From<&[T; 12]> for Mat4x3<T> {
+    fn from(slice: &[T; 12]) -> Self {
+        Mat4x3 {
+            x: Vec3 { x: slice[0],y: slice[1],z: slice[2], },
+            y: Vec3 { x: slice[3],y: slice[4],z: slice[5], },
+            z: Vec3 { x: slice[6],y: slice[7],z: slice[8], },
+            w: Vec3 { x: slice[9],y: slice[10],z: slice[11], },
+        }
+    }
+}
+
+impl<T> PartialEq for Mat4x3<T> where Vec3<T>: PartialEq {
+    fn eq(&self,other: &Self) -> bool {
+        (self.x == other.x) && (self.y == other.y) && (self.z == other.z) && (self.w == other.w)
+    }
+}
+
+impl<T> Display for Mat4x3<T> where Vec3<T>: Display {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"[{},{},{},{}]",self.x,self.y,self.z,self.w)
+    }
+}
+
+// matrix + matrix
+impl<T> Add<Mat4x3<T>> for Mat4x3<T> where Vec3<T>: Add<Vec3<T>,Output=Vec3<T>> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self {
+        Mat4x3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+// matrix += matrix
+impl<T> AddAssign<Mat4x3<T>> for Mat4x3<T> where Vec3<T>: AddAssign<Vec3<T>> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
+// matrix - matrix
+impl<T> Sub<Mat4x3<T>> for Mat4x3<T> where Vec3<T>: Sub<Vec3<T>,Output=Vec3<T>> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self {
+        Mat4x3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+// matrix -= matrix
+impl<T> SubAssign<Mat4x3<T>> for Mat4x3<T> where Vec3<T>: SubAssign<Vec3<T>> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
+// scalar * matrix
+macro_rules! scalar_mat4x3_mul {
+    ($($t:ty)+) => {
+        $(
+            impl Mul<Mat4x3<$t>> for $t {
+                type Output = Mat4x3<$t>;
+                fn mul(self,other: Mat4x3<$t>) -> Mat4x3<$t> {
+                    Mat4x3 {
+                        x: self * other.x,
+                        y: self * other.y,
+                        z: self * other.z,
+                        w: self * other.w,
+                    }
+                }
+            }
+        )+
+    }
+}
+
+scalar_mat4x3_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
+
+// matrix * scalar
+impl<T: Copy> Mul<T> for Mat4x3<T> where Vec3<T>: Mul<T,Output=Vec3<T>> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self {
+        Mat4x3 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+// matrix * vector
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec4<T>> for Mat4x3<T> {
+    type Output = Vec3<T>;
+    fn mul(self,other: Vec4<T>) -> Vec3<T> {
+        Vec3 {
+            x: self.x.x * other.x + self.y.x * other.y + self.z.x * other.z + self.w.x * other.w,
+            y: self.x.y * other.x + self.y.y * other.y + self.z.y * other.z + self.w.y * other.w,
+            z: self.x.z * other.x + self.y.z * other.y + self.z.z * other.z + self.w.z * other.w,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat3x4<T>> for Mat4x3<T> {
+    type Output = Mat3x3<T>;
+    fn mul(self,other: Mat3x4<T>) -> Mat3x3<T> {
+        Mat3x3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat4x4<T>> for Mat4x3<T> {
+    type Output = Mat4x3<T>;
+    fn mul(self,other: Mat4x4<T>) -> Mat4x3<T> {
+        Mat4x3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
+        }
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat4x3<T>> for Mat3x3<T> {
+    type Output = Mat4x3<T>;
+    fn mul(self,other: Mat4x3<T>) -> Mat4x3<T> {
+        Mat4x3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
+        }
+    }
+}
+
+// matrix *= scalar
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat4x3<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x.x *= other;
+        self.x.y *= other;
+        self.x.z *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.y.z *= other;
+        self.z.x *= other;
+        self.z.y *= other;
+        self.z.z *= other;
+        self.w.x *= other;
+        self.w.y *= other;
+        self.w.z *= other;
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat4x4<T>> for Mat4x3<T> {
+    fn mul_assign(&mut self,other: Mat4x4<T>) {
+        *self = *self * other;
+    }
+}
+
+// matrix / scalar
+impl<T: Copy + Div<T,Output=T>> Div<T> for Mat4x3<T> {
+    type Output = Mat4x3<T>;
+    fn div(self,other: T) -> Mat4x3<T> {
+        Mat4x3 {
+            x: Vec3 { x: self.x.x / other,y: self.x.y / other,z: self.x.z / other, },
+            y: Vec3 { x: self.y.x / other,y: self.y.y / other,z: self.y.z / other, },
+            z: Vec3 { x: self.z.x / other,y: self.z.y / other,z: self.z.z / other, },
+            w: Vec3 { x: self.w.x / other,y: self.w.y / other,z: self.w.z / other, },
+        }
+    }
+}
+
+// matrix /= scalar
+impl<T: Copy + DivAssign<T>> DivAssign<T> for Mat4x3<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x.x /= other;
+        self.x.y /= other;
+        self.x.z /= other;
+        self.y.x /= other;
+        self.y.y /= other;
+        self.y.z /= other;
+        self.z.x /= other;
+        self.z.y /= other;
+        self.z.z /= other;
+        self.w.x /= other;
+        self.w.y /= other;
+        self.w.z /= other;
+    }
+}
+
+// -matrix
+impl<T: Neg<Output=T>> Neg for Mat4x3<T> {
+    type Output = Mat4x3<T>;
+    fn neg(self) -> Mat4x3<T> {
+        Mat4x3 {
+            x: Vec3 { x: -self.x.x,y: -self.x.y,z: -self.x.z, },
+            y: Vec3 { x: -self.y.x,y: -self.y.y,z: -self.y.z, },
+            z: Vec3 { x: -self.z.x,y: -self.z.y,z: -self.z.z, },
+            w: Vec3 { x: -self.w.x,y: -self.w.y,z: -self.w.z, },
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32m4x3 = Mat4x3<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m4x3 = Mat4x3<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mat3x4(m: Mat3x4<f64>) -> Matrix<f64,4,3> {
+        Matrix([[m.x.x,m.x.y,m.x.z,m.x.w],[m.y.x,m.y.y,m.y.z,m.y.w],[m.z.x,m.z.y,m.z.z,m.z.w]])
+    }
+
+    fn mat4x3(m: Mat4x3<f64>) -> Matrix<f64,3,4> {
+        Matrix([[m.x.x,m.x.y,m.x.z],[m.y.x,m.y.y,m.y.z],[m.z.x,m.z.y,m.z.z],[m.w.x,m.w.y,m.w.z]])
+    }
+
+    #[test]
+    fn products_match_matrix() {
+        let a = Mat4x3::from([2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0]);
+        let b = Vec4 { x: -2.0,y: 3.0,z: -3.0,w: 2.0, };
+        assert!(mat4x3(a) * VecN::from(b) == VecN::from(a * b));
+        let a = Mat4x3::from([5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0]);
+        let b = Mat3x4::from([1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0]);
+        assert!(mat4x3(a) * mat3x4(b) == Matrix::from(a * b));
+        let a = Mat4x3::from([-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        let b = Mat4x4::from([4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0]);
+        assert!(mat4x3(a) * Matrix::from(b) == mat4x3(a * b));
+        let a = Mat3x3::from([0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0]);
+        let b = Mat4x3::from([-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0]);
+        assert!(Matrix::from(a) * mat4x3(b) == mat4x3(a * b));
+        let mut a = Mat4x3::from([3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0]);
+        let b = Mat4x4::from([-1.0,4.0,-2.0,3.0,-3.0,2.0,-4.0,1.0,-5.0,0.0,5.0,-1.0,4.0,-2.0,3.0,-3.0]);
+        let expected = mat4x3(a) * Matrix::from(b);
+        a *= b;
+        assert!(mat4x3(a) == expected);
+    }
+}
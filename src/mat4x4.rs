@@ -17,10 +17,75 @@ use {
             MulAssign,
             DivAssign,
             Neg,
+            Index,
+            IndexMut,
         },
     },
 };
 
+/// Handedness of a coordinate system.
+///
+/// In view space, a right-handed camera (OpenGL convention) looks down -Z, and a left-handed camera (Direct3D convention)
+/// looks down +Z. In both cases +Y is up.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub enum Handedness {
+    /// right-handed, looking down -Z.
+    Right,
+    /// left-handed, looking down +Z.
+    Left,
+}
+
+impl Handedness {
+
+    /// Z component of the viewing direction, -1 or 1.
+    pub(crate) fn forward<T: One + Neg<Output=T>>(self) -> T {
+        match self {
+            Handedness::Right => -T::ONE,
+            Handedness::Left => T::ONE,
+        }
+    }
+}
+
+/// Range of the normalized device depth after projection.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub enum DepthRange {
+    /// depth from 0 to 1 (Direct3D, Vulkan, Metal).
+    ZeroToOne,
+    /// depth from -1 to 1 (OpenGL).
+    NegativeOneToOne,
+}
+
+/// Edges of a view volume, for [`Mat4x4::frustum`] and [`Mat4x4::orthographic`].
+///
+/// `left`, `right`, `bottom` and `top` are in view space, and `near` and `far` are the (positive) distances to the
+/// clipping planes along the viewing direction.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct ViewVolume<T> {
+    pub left: T,
+    pub right: T,
+    pub bottom: T,
+    pub top: T,
+    pub near: T,
+    pub far: T,
+}
+
+/// Translation, rotation and scale of an affine transformation, as returned by [`Mat4x4::decompose`].
+#[derive(Copy,Clone,Debug)]
+pub struct Decomposition<T> {
+    pub translation: Vec3<T>,
+    pub rotation: Quaternion<T>,
+    /// scale along the rotated axes, with X negative if the transformation mirrors.
+    pub scale: Vec3<T>,
+    /// whether the transformation mirrors (has a negative determinant).
+    pub negative: bool,
+}
+
+/// 4x4 matrix template.
+///
+/// The matrix is stored as four column vectors `x`, `y`, `z` and `w`, so `m.y.x` is the element in the first row of the
+/// second column. A matrix transforms a vector by `m * v`, and `a * b` applies `b` first.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
 pub struct Mat4x4<T> {
     pub x: Vec4<T>,
@@ -29,9 +94,28 @@ pub struct Mat4x4<T> {
     pub w: Vec4<T>,
 }
 
-/*
-impl<T: PartialEq + Zero + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> + Neg<Output=T>> Mat4x4<T> {
+impl<T: Zero + One> Mat4x4<T> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Mat4x4 {
+        x: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        y: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        z: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        w: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+    };
+
+    /// identity matrix.
+    pub const IDENTITY: Self = Mat4x4 {
+        x: Vec4 { x: T::ONE,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+        y: Vec4 { x: T::ZERO,y: T::ONE,z: T::ZERO,w: T::ZERO, },
+        z: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ONE,w: T::ZERO, },
+        w: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ONE, },
+    };
+}
+
+impl<T: Copy> Mat4x4<T> {
 
+    /// transpose.
     pub fn transpose(self) -> Mat4x4<T> {
         Mat4x4 {
             x: Vec4 { x: self.x.x,y: self.y.x,z: self.z.x,w: self.w.x, },
@@ -41,7 +125,157 @@ impl<T: PartialEq + Zero + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         }
     }
 
+    /// upper left 3x3 part.
+    pub(crate) fn linear(self) -> Mat3x3<T> {
+        Mat3x3 {
+            x: Vec3 { x: self.x.x,y: self.x.y,z: self.x.z, },
+            y: Vec3 { x: self.y.x,y: self.y.y,z: self.y.z, },
+            z: Vec3 { x: self.z.x,y: self.z.y,z: self.z.z, },
+        }
+    }
+
+    /// build a matrix from `f(row,col)` for each element.
+    pub fn from_fn<F: FnMut(usize,usize) -> T>(mut f: F) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: f(0,0),y: f(1,0),z: f(2,0),w: f(3,0), },
+            y: Vec4 { x: f(0,1),y: f(1,1),z: f(2,1),w: f(3,1), },
+            z: Vec4 { x: f(0,2),y: f(1,2),z: f(2,2),w: f(3,2), },
+            w: Vec4 { x: f(0,3),y: f(1,3),z: f(2,3),w: f(3,3), },
+        }
+    }
+
+    /// apply `f` to each element.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Mat4x4<U> {
+        Mat4x4 { x: self.x.map(&mut f),y: self.y.map(&mut f),z: self.z.map(&mut f),w: self.w.map(&mut f), }
+    }
+
+    /// row `i`.
+    pub fn row(self,i: usize) -> Vec4<T> {
+        Vec4 { x: self[(i,0)],y: self[(i,1)],z: self[(i,2)],w: self[(i,3)], }
+    }
+
+    /// column `j`.
+    pub fn col(self,j: usize) -> Vec4<T> {
+        Vec4 { x: self[(0,j)],y: self[(1,j)],z: self[(2,j)],w: self[(3,j)], }
+    }
+
+    /// replace row `i`.
+    pub fn set_row(&mut self,i: usize,row: Vec4<T>) {
+        self[(i,0)] = row.x;
+        self[(i,1)] = row.y;
+        self[(i,2)] = row.z;
+        self[(i,3)] = row.w;
+    }
+
+    /// replace column `j`.
+    pub fn set_col(&mut self,j: usize,col: Vec4<T>) {
+        self[(0,j)] = col.x;
+        self[(1,j)] = col.y;
+        self[(2,j)] = col.z;
+        self[(3,j)] = col.w;
+    }
+
+    /// main diagonal.
+    pub fn diagonal(self) -> Vec4<T> {
+        Vec4 { x: self.x.x,y: self.y.y,z: self.z.z,w: self.w.w, }
+    }
+
+    /// iterate over the elements, column by column.
+    pub fn iter(&self) -> impl Iterator<Item=&T> {
+        IntoIterator::into_iter([
+            &self.x.x,&self.x.y,&self.x.z,&self.x.w,
+            &self.y.x,&self.y.y,&self.y.z,&self.y.w,
+            &self.z.x,&self.z.y,&self.z.z,&self.z.w,
+            &self.w.x,&self.w.y,&self.w.z,&self.w.w,
+        ])
+    }
+}
+
+impl<T: Copy + Zero> Mat4x4<T> {
+
+    /// diagonal matrix with `diagonal` on the main diagonal.
+    pub fn from_diagonal(diagonal: Vec4<T>) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: diagonal.x,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+            y: Vec4 { x: T::ZERO,y: diagonal.y,z: T::ZERO,w: T::ZERO, },
+            z: Vec4 { x: T::ZERO,y: T::ZERO,z: diagonal.z,w: T::ZERO, },
+            w: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: diagonal.w, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + One> Mat4x4<T> {
+
+    /// affine transformation with linear part `linear` followed by `translation`.
+    pub(crate) fn from_linear(linear: Mat3x3<T>,translation: Vec3<T>) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: linear.x.x,y: linear.x.y,z: linear.x.z,w: T::ZERO, },
+            y: Vec4 { x: linear.y.x,y: linear.y.y,z: linear.y.z,w: T::ZERO, },
+            z: Vec4 { x: linear.z.x,y: linear.z.y,z: linear.z.z,w: T::ZERO, },
+            w: Vec4 { x: translation.x,y: translation.y,z: translation.z,w: T::ONE, },
+        }
+    }
+
+    /// translation matrix.
+    pub fn from_translation(translation: Vec3<T>) -> Self {
+        let mut m = Self::IDENTITY;
+        m.w = Vec4 { x: translation.x,y: translation.y,z: translation.z,w: T::ONE, };
+        m
+    }
+
+    /// scale matrix.
+    pub fn from_scale(scale: Vec3<T>) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: scale.x,y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+            y: Vec4 { x: T::ZERO,y: scale.y,z: T::ZERO,w: T::ZERO, },
+            z: Vec4 { x: T::ZERO,y: T::ZERO,z: scale.z,w: T::ZERO, },
+            w: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ONE, },
+        }
+    }
+
+    /// shear matrix, where `xy` is how far x moves for each unit of y, `xz` how far x moves for each unit of z, and so
+    /// on.
+    pub fn from_shear(xy: T,xz: T,yx: T,yz: T,zx: T,zy: T) -> Self {
+        Mat4x4 {
+            x: Vec4 { x: T::ONE,y: yx,z: zx,w: T::ZERO, },
+            y: Vec4 { x: xy,y: T::ONE,z: zy,w: T::ZERO, },
+            z: Vec4 { x: xz,y: yz,z: T::ONE,w: T::ZERO, },
+            w: Vec4 { x: T::ZERO,y: T::ZERO,z: T::ZERO,w: T::ONE, },
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Real> Mat4x4<T> {
+
+    /// rotation of `angle` radians around unit-length `axis`.
+    pub fn from_axis_angle(axis: Vec3<T>,angle: T) -> Self {
+        let (s,c) = (angle / (T::ONE + T::ONE)).sin_cos();
+        Self::from_quaternion(Quaternion { r: c,i: axis.x * s,j: axis.y * s,k: axis.z * s, })
+    }
+
+    /// rotation matrix of a unit quaternion.
+    pub fn from_quaternion(rotation: Quaternion<T>) -> Self {
+        Self::from_linear(rotation.into(),Vec3 { x: T::ZERO,y: T::ZERO,z: T::ZERO, })
+    }
+
+    /// scale, then rotation, then translation.
+    pub fn from_trs(translation: Vec3<T>,rotation: Quaternion<T>,scale: Vec3<T>) -> Self {
+        let mut linear = Mat3x3::from(rotation);
+        linear.x = linear.x * scale.x;
+        linear.y = linear.y * scale.y;
+        linear.z = linear.z * scale.z;
+        Self::from_linear(linear,translation)
+    }
+}
+
+impl<T: Copy + Zero + PartialEq + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T>> Mat4x4<T> {
+
+    /// determinant.
     pub fn determinant(self) -> T {
+        // xx  yx  zx  wx
+        // xy  yy  zy  wy
+        // xz  yz  zz  wz
+        // xw  yw  zw  ww
         let xx = self.x.x;
         let xy = self.x.y;
         let xz = self.x.z;
@@ -58,14 +292,31 @@ impl<T: PartialEq + Zero + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         let wy = self.w.y;
         let wz = self.w.z;
         let ww = self.w.w;
-        let axx = yy * (zz * ww - wz * zw) - yz * (zw * wy - ww * zy) + yw * (zy * wz - wy * zz);
-        let axy = -(yz * (zw * wx - ww * zx) - yw * (zx * wz - wx * zz) + yx * (zz * ww - wz * zw));
-        let axz = yw * (zx * wy - wx * zy) - yx * (zy * ww - wy * zw) + yy * (zw * wx - ww * zx);
-        let axw = -(yx * (zy * wz - wy * zz) - yy * (zz * wx - wz * wx) + yz * (wx * wy - wx * zy));
+
+        // 2x2 determinants of the last two columns
+        let sxy = zx * wy - zy * wx;
+        let sxz = zx * wz - zz * wx;
+        let sxw = zx * ww - zw * wx;
+        let syz = zy * wz - zz * wy;
+        let syw = zy * ww - zw * wy;
+        let szw = zz * ww - zw * wz;
+
+        // cofactors of the first column
+        let axx = yy * szw - yz * syw + yw * syz;
+        let axy = -(yx * szw - yz * sxw + yw * sxz);
+        let axz = yx * syw - yy * sxw + yw * sxy;
+        let axw = -(yx * syz - yy * sxz + yz * sxy);
+
+        // determinant
         xx * axx + xy * axy + xz * axz + xw * axw
     }
 
-    pub fn inverse(self) -> Self {
+    /// cofactor matrix, where each element is replaced by its cofactor.
+    pub fn cofactor_matrix(self) -> Self {
+        // xx  yx  zx  wx
+        // xy  yy  zy  wy
+        // xz  yz  zz  wz
+        // xw  yw  zw  ww
         let xx = self.x.x;
         let xy = self.x.y;
         let xz = self.x.z;
@@ -82,36 +333,324 @@ impl<T: PartialEq + Zero + Add<T,Output=T> + Sub<T,Output=T> + Mul<T,Output=T> +
         let wy = self.w.y;
         let wz = self.w.z;
         let ww = self.w.w;
-        let axx = yy * (zz * ww - wz * zw) - yz * (zw * wy - ww * zy) + yw * (zy * wz - wy * zz);
-        let axy = -(yz * (zw * wx - ww * zx) - yw * (zx * wz - wx * zz) + yx * (zz * ww - wz * zw));
-        let axz = yw * (zx * wy - wx * zy) - yx * (zy * ww - wy * zw) + yy * (zw * wx - ww * zx);
-        let axw = -(yx * (zy * wz - wy * zz) - yy * (zz * wx - wz * wx) + yz * (wx * wy - wx * zy));
-        let det = xx * axx + xy * axy + xz * axz + xw * axw;
-        if det == T::ZERO {
-            return self;
-        }
-        let ayx = -(zy * (wz * xw - xz * ww) - zz * (ww * xy - xw * wy) + zw * (wy * xz - xy * wz));
-        let ayy = zz * (ww * xx - xw * wx) - zw * (wx * xz - xx * wz) + zx * (wz * xw - xz * ww);
-        let ayz = -(zw * (wx * xy - xx * wy) - zx * (wy * xw - xy * ww) + zy * (ww * xx - xw * wx));
-        let ayw = zx * (wy * xz - xy * wz) - zy * (wz * xx - xz * wx) + zz * (wx * xy - xx * wy);
-        let azx = wy * (xz * yw - yz * xw) - wz * (xw * yy - yz * xy) + ww * (xy * yz - yy * xz);
-        let azy = -(wz * (xw * yx - yw * xx) - ww * (xx * yz - yx * xz) + wx * (xz * yw - yz * xw));
-        let azz = ww * (xx * yy - yx * xy) - wx * (xy * yw - yy * xw) + wy * (xw * yx - yw * xx);
-        let azw = -(wx * (xy * yz - yy * xz) - wy * (xz * yx - yz * xx) + wz * (xx * yy - yx * xy));
-        let awx = -(xy * (yz * zw - zz * yw) - xz * (yw * zy - zw * yy) + xw * (yy * zz - zy * yz));
-        let awy = xz * (yw * zx - zw * yx) - xw * (yx * zz - zx * yz) + xx * (yz * zw - zz * yw);
-        let awz = -(xw * (yx * zy - zx * yy) - xx * (yy * zw - zy * yw) + xy * (yw * zx - zw * yx));
-        let aww = xx * (yy * zz - zy * yz) - xy * (yz * zx - zz * yx) + xz * (yx * zy - zx * yy);
+
+        // 2x2 determinants of the last two columns
+        let sxy = zx * wy - zy * wx;
+        let sxz = zx * wz - zz * wx;
+        let sxw = zx * ww - zw * wx;
+        let syz = zy * wz - zz * wy;
+        let syw = zy * ww - zw * wy;
+        let szw = zz * ww - zw * wz;
+
+        // 2x2 determinants of the first two columns
+        let txy = xx * yy - xy * yx;
+        let txz = xx * yz - xz * yx;
+        let txw = xx * yw - xw * yx;
+        let tyz = xy * yz - xz * yy;
+        let tyw = xy * yw - xw * yy;
+        let tzw = xz * yw - xw * yz;
+
+        // cofactors
+        let axx = yy * szw - yz * syw + yw * syz;
+        let axy = -(yx * szw - yz * sxw + yw * sxz);
+        let axz = yx * syw - yy * sxw + yw * sxy;
+        let axw = -(yx * syz - yy * sxz + yz * sxy);
+        let ayx = -(xy * szw - xz * syw + xw * syz);
+        let ayy = xx * szw - xz * sxw + xw * sxz;
+        let ayz = -(xx * syw - xy * sxw + xw * sxy);
+        let ayw = xx * syz - xy * sxz + xz * sxy;
+        let azx = wy * tzw - wz * tyw + ww * tyz;
+        let azy = -(wx * tzw - wz * txw + ww * txz);
+        let azz = wx * tyw - wy * txw + ww * txy;
+        let azw = -(wx * tyz - wy * txz + wz * txy);
+        let awx = -(zy * tzw - zz * tyw + zw * tyz);
+        let awy = zx * tzw - zz * txw + zw * txz;
+        let awz = -(zx * tyw - zy * txw + zw * txy);
+        let aww = zx * tyz - zy * txz + zz * txy;
+
         Mat4x4 {
             x: Vec4 { x: axx,y: axy,z: axz,w: axw, },
             y: Vec4 { x: ayx,y: ayy,z: ayz,w: ayw, },
             z: Vec4 { x: azx,y: azy,z: azz,w: azw, },
             w: Vec4 { x: awx,y: awy,z: awz,w: aww, },
-        } / det
+        }
+    }
+
+    /// adjugate (transpose of the cofactor matrix), which is the inverse times the determinant, even for singular
+    /// matrices.
+    pub fn adjugate(self) -> Self {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// inverse, or `None` if the matrix cannot be inverted.
+    pub fn try_inverse(self) -> Option<Self> {
+        let a = self.cofactor_matrix();
+
+        // determinant
+        let det = self.x.x * a.x.x + self.x.y * a.x.y + self.x.z * a.x.z + self.x.w * a.x.w;
+        if det == T::ZERO {
+            return None;
+        }
+
+        // transpose of cofactors divided by determinant
+        Some(a.transpose() / det)
+    }
+
+    /// inverse, or the matrix itself if it cannot be inverted.
+    ///
+    /// Prefer [`Mat4x4::try_inverse`] or [`Mat4x4::inverse_checked`] when the matrix might be singular.
+    pub fn inverse(self) -> Self {
+        self.try_inverse().unwrap_or(self)
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix cannot be inverted.
+    pub fn inverse_checked(self) -> std::result::Result<Self,SingularMatrixError> {
+        self.try_inverse().ok_or(SingularMatrixError)
+    }
+
+    /// matrix that transforms normals, the cofactor matrix of the upper left 3x3 part.
+    ///
+    /// This is the inverse transpose scaled by the determinant, so transformed normals need to be normalized. Unlike the
+    /// inverse transpose it stays valid when the upper left 3x3 part is singular (like after scaling an axis to zero),
+    /// and for mirroring transformations the normals keep matching the winding of the transformed triangles.
+    pub fn normal_matrix(self) -> Mat3x3<T> {
+        self.linear().cofactor_matrix()
+    }
+}
+
+impl<T: Copy + Zero + One + PartialEq + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat4x4<T> {
+
+    /// condition number estimate in the 1-norm, see [`Matrix::condition_number`].
+    pub fn condition_number(self) -> T {
+        Matrix::from(self).condition_number()
+    }
+
+    /// inverse, or [`SingularMatrixError`] if the matrix is singular or too close to singular, see
+    /// [`Matrix::inverse_with_tolerance`].
+    pub fn inverse_with_tolerance(self,tolerance: T) -> std::result::Result<Self,SingularMatrixError> {
+        Matrix::from(self).inverse_with_tolerance(tolerance).map(Mat4x4::from)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialEq + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat4x4<T> {
+
+    /// projection matrix from the third and fourth column parts.
+    ///
+    /// Maps the view space position `(x,y,z)` to clip space with `w` = `s * z` (with `s` the forward direction from
+    /// `handedness`) and depth `a * s * z + b`.
+    fn projection(x: Vec2<T>,y: Vec2<T>,center: Vec2<T>,a: T,b: T,handedness: Handedness) -> Self {
+        let s: T = handedness.forward();
+        Mat4x4 {
+            x: Vec4 { x: x.x,y: x.y,z: T::ZERO,w: T::ZERO, },
+            y: Vec4 { x: y.x,y: y.y,z: T::ZERO,w: T::ZERO, },
+            z: Vec4 { x: -s * center.x,y: -s * center.y,z: a * s,w: s, },
+            w: Vec4 { x: T::ZERO,y: T::ZERO,z: b,w: T::ZERO, },
+        }
+    }
+
+    /// perspective projection.
+    ///
+    /// `fov_y` is the vertical field of view in radians, `aspect` is width divided by height, and `near` and `far` are the
+    /// (positive) distances to the clipping planes.
+    pub fn perspective(fov_y: T,aspect: T,near: T,far: T,handedness: Handedness,depth: DepthRange) -> Self {
+        let two = T::ONE + T::ONE;
+        let f = T::ONE / (fov_y / two).tan();
+        let (a,b) = match depth {
+            DepthRange::ZeroToOne => (far / (far - near),-near * far / (far - near)),
+            DepthRange::NegativeOneToOne => ((far + near) / (far - near),-two * far * near / (far - near)),
+        };
+        Self::projection(Vec2 { x: f / aspect,y: T::ZERO, },Vec2 { x: T::ZERO,y: f, },Vec2 { x: T::ZERO,y: T::ZERO, },a,b,handedness)
+    }
+
+    /// perspective projection with the far plane at infinity.
+    pub fn perspective_infinite(fov_y: T,aspect: T,near: T,handedness: Handedness,depth: DepthRange) -> Self {
+        let two = T::ONE + T::ONE;
+        let f = T::ONE / (fov_y / two).tan();
+        let b = match depth {
+            DepthRange::ZeroToOne => -near,
+            DepthRange::NegativeOneToOne => -two * near,
+        };
+        Self::projection(Vec2 { x: f / aspect,y: T::ZERO, },Vec2 { x: T::ZERO,y: f, },Vec2 { x: T::ZERO,y: T::ZERO, },T::ONE,b,handedness)
+    }
+
+    /// perspective projection with reversed depth, so `near` maps to the far end of the depth range and `far` to the
+    /// near end.
+    ///
+    /// Combined with [`DepthRange::ZeroToOne`] and a floating point depth buffer, this spreads the depth precision much
+    /// more evenly over the view distance.
+    pub fn perspective_reverse_z(fov_y: T,aspect: T,near: T,far: T,handedness: Handedness,depth: DepthRange) -> Self {
+        let two = T::ONE + T::ONE;
+        let f = T::ONE / (fov_y / two).tan();
+        let (a,b) = match depth {
+            DepthRange::ZeroToOne => (-near / (far - near),near * far / (far - near)),
+            DepthRange::NegativeOneToOne => (-(far + near) / (far - near),two * far * near / (far - near)),
+        };
+        Self::projection(Vec2 { x: f / aspect,y: T::ZERO, },Vec2 { x: T::ZERO,y: f, },Vec2 { x: T::ZERO,y: T::ZERO, },a,b,handedness)
+    }
+
+    /// perspective projection of an off-center view volume.
+    ///
+    /// `left`, `right`, `bottom` and `top` of `volume` describe the edges of the view volume on the near plane.
+    pub fn frustum(volume: ViewVolume<T>,handedness: Handedness,depth: DepthRange) -> Self {
+        let ViewVolume { left,right,bottom,top,near,far, } = volume;
+        let two = T::ONE + T::ONE;
+        let (a,b) = match depth {
+            DepthRange::ZeroToOne => (far / (far - near),-near * far / (far - near)),
+            DepthRange::NegativeOneToOne => ((far + near) / (far - near),-two * far * near / (far - near)),
+        };
+        Self::projection(
+            Vec2 { x: two * near / (right - left),y: T::ZERO, },
+            Vec2 { x: T::ZERO,y: two * near / (top - bottom), },
+            Vec2 { x: (right + left) / (right - left),y: (top + bottom) / (top - bottom), },
+            a,
+            b,
+            handedness,
+        )
+    }
+
+    /// orthographic projection of `volume`.
+    pub fn orthographic(volume: ViewVolume<T>,handedness: Handedness,depth: DepthRange) -> Self {
+        let ViewVolume { left,right,bottom,top,near,far, } = volume;
+        let two = T::ONE + T::ONE;
+        let s: T = handedness.forward();
+        let (a,b) = match depth {
+            DepthRange::ZeroToOne => (T::ONE / (far - near),-near / (far - near)),
+            DepthRange::NegativeOneToOne => (two / (far - near),-(far + near) / (far - near)),
+        };
+        Mat4x4 {
+            x: Vec4 { x: two / (right - left),y: T::ZERO,z: T::ZERO,w: T::ZERO, },
+            y: Vec4 { x: T::ZERO,y: two / (top - bottom),z: T::ZERO,w: T::ZERO, },
+            z: Vec4 { x: T::ZERO,y: T::ZERO,z: a * s,w: T::ZERO, },
+            w: Vec4 { x: -(right + left) / (right - left),y: -(top + bottom) / (top - bottom),z: b,w: T::ONE, },
+        }
+    }
+
+    /// vertical field of view in radians of a perspective projection.
+    pub fn projection_fov_y(self) -> T {
+        let two = T::ONE + T::ONE;
+        two * (T::ONE / self.y.y).atan()
+    }
+
+    /// aspect ratio (width divided by height) of a projection.
+    pub fn projection_aspect(self) -> T {
+        self.y.y / self.x.x
+    }
+
+    /// near and far distances of a projection built with `handedness` and `depth`.
+    ///
+    /// Works for all projections above; for [`Mat4x4::perspective_infinite`] the far distance is infinity, and for
+    /// [`Mat4x4::perspective_reverse_z`] the two distances come out in the right order as well.
+    pub fn projection_near_far(self,handedness: Handedness,depth: DepthRange) -> (T,T) {
+        let two = T::ONE + T::ONE;
+        let s: T = handedness.forward();
+        let a = self.z.z * s;
+        let b = self.w.z;
+
+        // orthographic
+        if self.z.w == T::ZERO {
+            return match depth {
+                DepthRange::ZeroToOne => {
+                    let near = b / -a;
+                    (near,near + T::ONE / a)
+                },
+                DepthRange::NegativeOneToOne => {
+                    let range = two / a;
+                    let sum = -b * range;
+                    ((sum - range) / two,(sum + range) / two)
+                },
+            };
+        }
+
+        // perspective
+        let (near,far) = match depth {
+            DepthRange::ZeroToOne => (-b / a,b / (T::ONE - a)),
+            DepthRange::NegativeOneToOne => (-b / (a + T::ONE),b / (T::ONE - a)),
+        };
+        if a == T::ONE {
+            (near,T::INFINITY)
+        }
+        else if near > far {
+            (far,near)
+        }
+        else {
+            (near,far)
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat4x4<T> {
+
+    /// view matrix for a camera at `eye` looking in direction `dir`, with +Y as close to `up` as possible.
+    ///
+    /// Still gives a valid view matrix when `dir` is parallel to `up`, see [`Mat3x3::look_rotation`].
+    pub fn look_to(eye: Vec3<T>,dir: Vec3<T>,up: Vec3<T>,handedness: Handedness) -> Self {
+        let r = Mat3x3::look_rotation(dir,up,handedness);
+        Mat4x4 {
+            x: Vec4 { x: r.x.x,y: r.y.x,z: r.z.x,w: T::ZERO, },
+            y: Vec4 { x: r.x.y,y: r.y.y,z: r.z.y,w: T::ZERO, },
+            z: Vec4 { x: r.x.z,y: r.y.z,z: r.z.z,w: T::ZERO, },
+            w: Vec4 { x: -r.x.dot(eye),y: -r.y.dot(eye),z: -r.z.dot(eye),w: T::ONE, },
+        }
+    }
+
+    /// view matrix for a camera at `eye` looking at `target`, with +Y as close to `up` as possible.
+    pub fn look_at(eye: Vec3<T>,target: Vec3<T>,up: Vec3<T>,handedness: Handedness) -> Self {
+        Self::look_to(eye,target - eye,up,handedness)
     }
 }
 
-impl<T> From<[Vec4<T>; 4]> for Mat4x4<T> {
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real> Mat4x4<T> {
+
+    /// split an affine transformation into translation, rotation and scale, the inverse of [`Mat4x4::from_trs`].
+    ///
+    /// If the transformation mirrors (negative determinant), the X scale is negative and `negative` is set. Any shear
+    /// or projective part is lost.
+    pub fn decompose(self) -> Decomposition<T> {
+        let translation = Vec3 { x: self.w.x,y: self.w.y,z: self.w.z, };
+        let x = Vec3 { x: self.x.x,y: self.x.y,z: self.x.z, };
+        let y = Vec3 { x: self.y.x,y: self.y.y,z: self.y.z, };
+        let z = Vec3 { x: self.z.x,y: self.z.y,z: self.z.z, };
+        let negative = x.scalar_triple(y,z) < T::ZERO;
+        let mut scale = Vec3 { x: x.length(),y: y.length(),z: z.length(), };
+        if negative {
+            scale.x = -scale.x;
+        }
+
+        // rotation axes, rebuilt from the others where the scale is 0
+        let axis = |v: Vec3<T>,length: T| if length != T::ZERO { Some(v / length) } else { None };
+        let (x,y,z) = match (axis(x,scale.x),axis(y,scale.y),axis(z,scale.z)) {
+            (Some(x),Some(y),Some(z)) => (x,y,z),
+            (None,Some(y),Some(z)) => (y.cross(z),y,z),
+            (Some(x),None,Some(z)) => (x,z.cross(x),z),
+            (Some(x),Some(y),None) => (x,y,x.cross(y)),
+            (Some(x),None,None) => {
+                let y = x.any_perpendicular();
+                (x,y,x.cross(y))
+            },
+            (None,Some(y),None) => {
+                let z = y.any_perpendicular();
+                (y.cross(z),y,z)
+            },
+            (None,None,Some(z)) => {
+                let x = z.any_perpendicular();
+                (x,z.cross(x),z)
+            },
+            (None,None,None) => {
+                let identity = Mat3x3::IDENTITY;
+                (identity.x,identity.y,identity.z)
+            },
+        };
+
+        Decomposition {
+            translation,
+            rotation: Mat3x3 { x,y,z, }.into(),
+            scale,
+            negative,
+        }
+    }
+}
+
+impl<T: Copy> From<[Vec4<T>; 4]> for Mat4x4<T> {
     fn from(array: [Vec4<T>; 4]) -> Self {
         Mat4x4 {
             x: array[0],
@@ -122,7 +661,7 @@ impl<T> From<[Vec4<T>; 4]> for Mat4x4<T> {
     }
 }
 
-impl<T> From<&[Vec4<T>; 4]> for Mat4x4<T> {
+impl<T: Copy> From<&[Vec4<T>; 4]> for Mat4x4<T> {
     fn from(slice: &[Vec4<T>; 4]) -> Self {
         Mat4x4 {
             x: slice[0],
@@ -133,7 +672,7 @@ impl<T> From<&[Vec4<T>; 4]> for Mat4x4<T> {
     }
 }
 
-impl<T> From<[T; 16]> for Mat4x4<T> {
+impl<T: Copy> From<[T; 16]> for Mat4x4<T> {
     fn from(array: [T; 16]) -> Self {
         Mat4x4 {
             x: Vec4 { x: array[0],y: array[1],z: array[2],w: array[3], },
@@ -144,7 +683,7 @@ impl<T> From<[T; 16]> for Mat4x4<T> {
     }
 }
 
-impl<T> From<&[T; 16]> for Mat4x4<T> {
+impl<T: Copy> From<&[T; 16]> for Mat4x4<T> {
     fn from(slice: &[T; 16]) -> Self {
         Mat4x4 {
             x: Vec4 { x: slice[0],y: slice[1],z: slice[2],w: slice[3], },
@@ -167,6 +706,45 @@ impl<T> Display for Mat4x4<T> where Vec4<T>: Display {
     }
 }
 
+impl<T> Index<(usize,usize)> for Mat4x4<T> {
+    type Output = T;
+    fn index(&self,(row,col): (usize,usize)) -> &T {
+        let column = match col {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &column.x,
+            1 => &column.y,
+            2 => &column.z,
+            3 => &column.w,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize,usize)> for Mat4x4<T> {
+    fn index_mut(&mut self,(row,col): (usize,usize)) -> &mut T {
+        let column = match col {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("column {} out of range",col),
+        };
+        match row {
+            0 => &mut column.x,
+            1 => &mut column.y,
+            2 => &mut column.z,
+            3 => &mut column.w,
+            _ => panic!("row {} out of range",row),
+        }
+    }
+}
+
 // matrix + matrix
 impl<T> Add<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: Add<Vec4<T>,Output=Vec4<T>> {
     type Output = Self;
@@ -180,6 +758,16 @@ impl<T> Add<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: Add<Vec4<T>,Output=Vec4<T>>
     }
 }
 
+// matrix += matrix
+impl<T> AddAssign<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: AddAssign<Vec4<T>> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
 // matrix - matrix
 impl<T> Sub<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: Sub<Vec4<T>,Output=Vec4<T>> {
     type Output = Self;
@@ -193,6 +781,16 @@ impl<T> Sub<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: Sub<Vec4<T>,Output=Vec4<T>>
     }
 }
 
+// matrix -= matrix
+impl<T> SubAssign<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: SubAssign<Vec4<T>> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
 // scalar * matrix
 macro_rules! scalar_mat4x4_mul {
     ($($t:ty)+) => {
@@ -212,10 +810,10 @@ macro_rules! scalar_mat4x4_mul {
     }
 }
 
-scalar_mat4x4_mul!(f32 f64);
+scalar_mat4x4_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
 
 // matrix * scalar
-impl<T> Mul<T> for Mat4x4<T> where Vec4<T>: Mul<T,Output=Vec4<T>> {
+impl<T: Copy> Mul<T> for Mat4x4<T> where Vec4<T>: Mul<T,Output=Vec4<T>> {
     type Output = Self;
     fn mul(self,other: T) -> Self {
         Mat4x4 {
@@ -228,14 +826,14 @@ impl<T> Mul<T> for Mat4x4<T> where Vec4<T>: Mul<T,Output=Vec4<T>> {
 }
 
 // matrix * vector
-impl<T: Add<T,Output=T> + Mul<T,Output=T>> Mul<Vec4<T>> for Mat4x4<T> {
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Vec4<T>> for Mat4x4<T> {
     type Output = Vec4<T>;
     fn mul(self,other: Vec4<T>) -> Vec4<T> {
         Vec4 {
-            x: self.x.x * other.x + self.x.y * other.y + self.x.z * other.z + self.x.w * other.w,
-            y: self.y.x * other.x + self.y.y * other.y + self.y.z * other.z + self.y.w * other.w,
-            z: self.z.x * other.x + self.z.y * other.y + self.z.z * other.z + self.z.w * other.w,
-            w: self.w.x * other.x + self.w.y * other.y + self.w.z * other.z + self.w.w * other.w,
+            x: self.x.x * other.x + self.y.x * other.y + self.z.x * other.z + self.w.x * other.w,
+            y: self.x.y * other.x + self.y.y * other.y + self.z.y * other.z + self.w.y * other.w,
+            z: self.x.z * other.x + self.y.z * other.y + self.z.z * other.z + self.w.z * other.w,
+            w: self.x.w * other.x + self.y.w * other.y + self.z.w * other.z + self.w.w * other.w,
         }
     }
 }
@@ -245,34 +843,43 @@ impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> Mul<Mat4x4<T>> for Mat4x4<T> {
     type Output = Mat4x4<T>;
     fn mul(self,other: Mat4x4<T>) -> Mat4x4<T> {
         Mat4x4 {
-            x: Vec4 {
-                x: self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x,
-                y: self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y,
-                z: self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z + self.x.w * other.w.z,
-                w: self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w + self.x.w * other.w.w,
-            },
-            y: Vec4 {
-                x: self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x,
-                y: self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y,
-                z: self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z + self.y.w * other.w.z,
-                w: self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w + self.y.w * other.w.w,
-            },
-            z: Vec4 {
-                x: self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x,
-                y: self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y,
-                z: self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z + self.z.w * other.w.z,
-                w: self.z.x * other.x.w + self.z.y * other.y.w + self.z.z * other.z.w + self.z.w * other.w.w,
-            },
-            w: Vec4 {
-                x: self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x + self.w.w * other.w.x,
-                y: self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y + self.w.w * other.w.y,
-                z: self.w.x * other.x.z + self.w.y * other.y.z + self.w.z * other.z.z + self.w.w * other.w.z,
-                w: self.w.x * other.x.w + self.w.y * other.y.w + self.w.z * other.z.w + self.w.w * other.w.w,
-            },
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
         }
     }
 }
 
+// matrix *= scalar
+impl<T: Copy + MulAssign<T>> MulAssign<T> for Mat4x4<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x.x *= other;
+        self.x.y *= other;
+        self.x.z *= other;
+        self.x.w *= other;
+        self.y.x *= other;
+        self.y.y *= other;
+        self.y.z *= other;
+        self.y.w *= other;
+        self.z.x *= other;
+        self.z.y *= other;
+        self.z.z *= other;
+        self.z.w *= other;
+        self.w.x *= other;
+        self.w.y *= other;
+        self.w.z *= other;
+        self.w.w *= other;
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat4x4<T>> for Mat4x4<T> {
+    fn mul_assign(&mut self,other: Mat4x4<T>) {
+        *self = *self * other;
+    }
+}
+
 // matrix / scalar
 impl<T: Copy + Div<T,Output=T>> Div<T> for Mat4x4<T> {
     type Output = Mat4x4<T>;
@@ -286,62 +893,6 @@ impl<T: Copy + Div<T,Output=T>> Div<T> for Mat4x4<T> {
     }
 }
 
-// matrix += matrix
-impl<T> AddAssign<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: AddAssign<Vec4<T>> {
-    fn add_assign(&mut self,other: Self) {
-        self.x += other.x;
-        self.y += other.y;
-        self.z += other.z;
-        self.w += other.w;
-    }
-}
-
-// matrix -= matrix
-impl<T> SubAssign<Mat4x4<T>> for Mat4x4<T> where Vec4<T>: SubAssign<Vec4<T>> {
-    fn sub_assign(&mut self,other: Self) {
-        self.x -= other.x;
-        self.y -= other.y;
-        self.z -= other.z;
-        self.w -= other.w;
-    }
-}
-
-// matrix *= scalar
-impl<T> MulAssign<T> for Mat4x4<T> where Vec4<T>: MulAssign<T> {
-    fn mul_assign(&mut self,other: T) {
-        self.x *= other;
-        self.y *= other;
-        self.z *= other;
-        self.w *= other;
-    }
-}
-
-// matrix *= matrix
-impl<T: Copy + Mul<T,Output=T> + Add<T,Output=T>> MulAssign<Mat4x4<T>> for Mat4x4<T> {
-    fn mul_assign(&mut self,other: Mat4x4<T>) {
-        let xx = self.x.x * other.x.x + self.x.y * other.y.x + self.x.z * other.z.x + self.x.w * other.w.x;
-        let xy = self.x.x * other.x.y + self.x.y * other.y.y + self.x.z * other.z.y + self.x.w * other.w.y;
-        let xz = self.x.x * other.x.z + self.x.y * other.y.z + self.x.z * other.z.z + self.x.w * other.w.z;
-        let xw = self.x.x * other.x.w + self.x.y * other.y.w + self.x.z * other.z.w + self.x.w * other.w.w;
-        self.x = Vec4 { x: xx,y: xy,z: xz,w: xw, };
-        let yx = self.y.x * other.x.x + self.y.y * other.y.x + self.y.z * other.z.x + self.y.w * other.w.x;
-        let yy = self.y.x * other.x.y + self.y.y * other.y.y + self.y.z * other.z.y + self.y.w * other.w.y;
-        let yz = self.y.x * other.x.z + self.y.y * other.y.z + self.y.z * other.z.z + self.y.w * other.w.z;
-        let yw = self.y.x * other.x.w + self.y.y * other.y.w + self.y.z * other.z.w + self.y.w * other.w.w;
-        self.y = Vec4 { x: yx,y: yy,z: yz,w: yw, };
-        let zx = self.z.x * other.x.x + self.z.y * other.y.x + self.z.z * other.z.x + self.z.w * other.w.x;
-        let zy = self.z.x * other.x.y + self.z.y * other.y.y + self.z.z * other.z.y + self.z.w * other.w.y;
-        let zz = self.z.x * other.x.z + self.z.y * other.y.z + self.z.z * other.z.z + self.z.w * other.w.z;
-        let zw = self.z.x * other.x.w + self.z.y * other.y.w + self.z.z * other.z.w + self.z.w * other.w.w;
-        self.z = Vec4 { x: zx,y: zy,z: zz,w: zw, };
-        let wx = self.w.x * other.x.x + self.w.y * other.y.x + self.w.z * other.z.x + self.w.w * other.w.x;
-        let wy = self.w.x * other.x.y + self.w.y * other.y.y + self.w.z * other.z.y + self.w.w * other.w.y;
-        let wz = self.w.x * other.x.z + self.w.y * other.y.z + self.w.z * other.z.z + self.w.w * other.w.z;
-        let ww = self.w.x * other.x.w + self.w.y * other.y.w + self.w.z * other.z.w + self.w.w * other.w.w;
-        self.w = Vec4 { x: wx,y: wy,z: wz,w: ww, };
-    }
-}
-
 // matrix /= scalar
 impl<T: Copy + DivAssign<T>> DivAssign<T> for Mat4x4<T> {
     fn div_assign(&mut self,other: T) {
@@ -376,4 +927,236 @@ impl<T: Neg<Output=T>> Neg for Mat4x4<T> {
         }
     }
 }
-*/
+
+#[allow(non_camel_case_types)]
+pub type f32m4 = Mat4x4<f32>;
+#[allow(non_camel_case_types)]
+pub type f64m4 = Mat4x4<f64>;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    #[test]
+    fn known_determinant_and_inverse() {
+        // m * m = 4 * I
+        let m = Mat4x4::from([
+            1.0,1.0,1.0,-1.0,
+            1.0,1.0,-1.0,1.0,
+            1.0,-1.0,1.0,1.0,
+            -1.0,1.0,1.0,1.0,
+        ]);
+        assert_eq!(m.determinant(),-16.0);
+        assert!(m.inverse() == m / 4.0);
+    }
+
+    #[test]
+    fn singular() {
+        let m = Mat4x4::from([
+            1.0,2.0,3.0,4.0,
+            5.0,6.0,7.0,8.0,
+            2.0,4.0,6.0,8.0,
+            0.0,1.0,0.0,1.0,
+        ]);
+        assert_eq!(m.determinant(),0.0);
+        assert!(m.try_inverse().is_none());
+        assert_eq!(m.inverse_checked(),Err(SingularMatrixError));
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Mat4x4::from([
+            2.0,0.5,-1.0,0.25,
+            1.0,3.0,0.0,-2.0,
+            -0.5,1.0,4.0,1.0,
+            3.0,-1.0,2.0,5.0,
+        ]);
+        assert!(close(m * m.inverse(),Mat4x4::IDENTITY));
+        assert!(close(m.inverse() * m,Mat4x4::IDENTITY));
+    }
+
+    /// normalized device depth of the point at `distance` along the viewing direction.
+    fn depth(m: Mat4x4<f64>,distance: f64,handedness: Handedness) -> f64 {
+        let clip = m * Vec4 { x: 0.0,y: 0.0,z: distance * handedness.forward::<f64>(),w: 1.0, };
+        clip.z / clip.w
+    }
+
+    fn check_near_far(m: Mat4x4<f64>,handedness: Handedness,depth_range: DepthRange,near: f64,far: f64) {
+        let (n,f) = m.projection_near_far(handedness,depth_range);
+        assert!((n - near).abs() < 1e-9 * near);
+        assert!((f - far).abs() < 1e-9 * far);
+    }
+
+    #[test]
+    fn projection_depth() {
+        let volume = ViewVolume { left: -1.0,right: 3.0,bottom: -2.0,top: 1.0,near: 0.5,far: 100.0, };
+        let (fov_y,aspect) = (1.0,1.5);
+        for handedness in [Handedness::Right,Handedness::Left] {
+            for depth_range in [DepthRange::ZeroToOne,DepthRange::NegativeOneToOne] {
+                let low = if depth_range == DepthRange::ZeroToOne { 0.0 } else { -1.0 };
+                let projections = [
+                    Mat4x4::perspective(fov_y,aspect,volume.near,volume.far,handedness,depth_range),
+                    Mat4x4::frustum(volume,handedness,depth_range),
+                    Mat4x4::orthographic(volume,handedness,depth_range),
+                ];
+                for m in projections {
+                    assert!((depth(m,volume.near,handedness) - low).abs() < 1e-12);
+                    assert!((depth(m,volume.far,handedness) - 1.0).abs() < 1e-12);
+                    check_near_far(m,handedness,depth_range,volume.near,volume.far);
+                }
+                let [perspective,frustum,orthographic] = projections;
+                assert!((perspective.projection_fov_y() - fov_y).abs() < 1e-12);
+                assert!((perspective.projection_aspect() - aspect).abs() < 1e-12);
+                let height = volume.top - volume.bottom;
+                assert!((frustum.projection_fov_y() - 2.0 * (height / (2.0 * volume.near)).atan()).abs() < 1e-12);
+                assert!((frustum.projection_aspect() - (volume.right - volume.left) / height).abs() < 1e-12);
+                assert!((orthographic.projection_aspect() - (volume.right - volume.left) / height).abs() < 1e-12);
+
+                let m = Mat4x4::perspective_reverse_z(fov_y,aspect,volume.near,volume.far,handedness,depth_range);
+                assert!((depth(m,volume.near,handedness) - 1.0).abs() < 1e-12);
+                assert!((depth(m,volume.far,handedness) - low).abs() < 1e-12);
+                assert!((m.projection_fov_y() - fov_y).abs() < 1e-12);
+                assert!((m.projection_aspect() - aspect).abs() < 1e-12);
+                check_near_far(m,handedness,depth_range,volume.near,volume.far);
+
+                let m = Mat4x4::perspective_infinite(fov_y,aspect,volume.near,handedness,depth_range);
+                assert!((depth(m,volume.near,handedness) - low).abs() < 1e-12);
+                assert!((depth(m,1e12,handedness) - 1.0).abs() < 1e-9);
+                assert!((m.projection_fov_y() - fov_y).abs() < 1e-12);
+                assert!((m.projection_aspect() - aspect).abs() < 1e-12);
+                let (near,far) = m.projection_near_far(handedness,depth_range);
+                assert!((near - volume.near).abs() < 1e-12);
+                assert!(far == f64::INFINITY);
+            }
+        }
+    }
+
+    #[test]
+    fn normal_matrix() {
+        // normals follow the cross product of transformed tangents
+        let m = Mat4x4::from_trs(
+            Vec3 { x: 1.0,y: 2.0,z: 3.0, },
+            Quaternion::look_rotation(Vec3 { x: 0.3,y: 0.1,z: 1.0, },Vec3 { x: 0.0,y: 1.0,z: 0.0, },Handedness::Right),
+            Vec3 { x: 2.0,y: 0.5,z: -3.0, },
+        );
+        let a = Vec3 { x: 1.0,y: 0.5,z: -1.0, };
+        let b = Vec3 { x: 0.0,y: 2.0,z: 1.0, };
+        let linear = m.linear();
+        let expected = (linear * a).cross(linear * b);
+        assert!((m.normal_matrix() * a.cross(b) - expected).length() < 1e-12);
+
+        // same direction as the inverse transpose when that exists
+        assert!(close(m.normal_matrix() / linear.determinant(),linear.inverse().transpose()));
+
+        // flattened geometry keeps its normal
+        let flat = Mat4x4::from_scale(Vec3 { x: 2.0,y: 3.0,z: 0.0, });
+        assert!(flat.normal_matrix() * Vec3 { x: 0.0,y: 0.0,z: 1.0, } == Vec3 { x: 0.0,y: 0.0,z: 6.0, });
+    }
+
+    #[test]
+    fn determinant_matches_lu() {
+        let m = Mat4x4::from([
+            2.0,0.5,-1.0,0.25,
+            1.0,3.0,0.0,-2.0,
+            -0.5,1.0,4.0,1.0,
+            3.0,-1.0,2.0,5.0,
+        ]);
+        assert!((m.determinant() - m.lu().determinant()).abs() < 1e-12);
+    }
+
+    // same rotation, allowing for the sign ambiguity of unit quaternions
+    fn same_rotation(a: Quaternion<f64>,b: Quaternion<f64>) -> bool {
+        ((a.r * b.r + a.i * b.i + a.j * b.j + a.k * b.k).abs() - 1.0).abs() < 1e-12
+    }
+
+    fn rotation() -> Quaternion<f64> {
+        let axis = Vec3 { x: 1.0,y: 2.0,z: -1.0, }.normalize();
+        let (s,c) = 0.35f64.sin_cos();
+        Quaternion { r: c,i: axis.x * s,j: axis.y * s,k: axis.z * s, }
+    }
+
+    #[test]
+    fn from_axis_angle() {
+        let m = Mat4x4::from_axis_angle(Vec3 { x: 0.0,y: 0.0,z: 1.0, },std::f64::consts::FRAC_PI_2);
+        assert!(close(m * Vec4 { x: 1.0,y: 0.0,z: 0.0,w: 1.0, },Vec4 { x: 0.0,y: 1.0,z: 0.0,w: 1.0, }));
+        assert!(close(m * Vec4 { x: 0.0,y: 1.0,z: 0.0,w: 1.0, },Vec4 { x: -1.0,y: 0.0,z: 0.0,w: 1.0, }));
+
+        // the same rotation as the quaternion, which keeps its axis
+        let axis = Vec3 { x: 1.0,y: 2.0,z: -1.0, }.normalize();
+        let m = Mat4x4::from_axis_angle(axis,0.7);
+        assert!(close(m,Mat4x4::from_quaternion(rotation())));
+        assert!(close(m * axis.extend(0.0),axis.extend(0.0)));
+    }
+
+    #[test]
+    fn from_shear() {
+        let m = Mat4x4::from_shear(0.5,-1.0,2.0,0.25,1.5,-0.5);
+        let p = Vec4 { x: 1.0,y: 2.0,z: 4.0,w: 1.0, };
+        assert!(m * p == Vec4 { x: 1.0 + 1.0 - 4.0,y: 2.0 + 2.0 + 1.0,z: 1.5 - 1.0 + 4.0,w: 1.0, });
+    }
+
+    #[test]
+    fn from_trs() {
+        let t = Vec3 { x: 1.0,y: -2.0,z: 3.0, };
+        let s = Vec3 { x: 2.0,y: 0.5,z: 3.0, };
+        let m = Mat4x4::from_trs(t,rotation(),s);
+        let expected = Mat4x4::from_translation(t) * Mat4x4::from_quaternion(rotation()) * Mat4x4::from_scale(s);
+        assert!(close(m,expected));
+    }
+
+    #[test]
+    fn decompose() {
+        let t = Vec3 { x: 1.0,y: -2.0,z: 3.0, };
+        let s = Vec3 { x: 2.0,y: 0.5,z: 3.0, };
+        let d = Mat4x4::from_trs(t,rotation(),s).decompose();
+        assert!(close(d.translation,t));
+        assert!(same_rotation(d.rotation,rotation()));
+        assert!(close(d.scale,s));
+        assert!(!d.negative);
+
+        // mirrored, the sign is folded into the X scale
+        let d = Mat4x4::from_trs(t,rotation(),Vec3 { x: -2.0,y: 0.5,z: 3.0, }).decompose();
+        assert!(d.negative);
+        assert!(close(d.scale,Vec3 { x: -2.0,y: 0.5,z: 3.0, }));
+        assert!(same_rotation(d.rotation,rotation()));
+        let m = Mat4x4::from_trs(t,rotation(),Vec3 { x: 2.0,y: -0.5,z: 3.0, });
+        let d = m.decompose();
+        assert!(d.negative);
+        assert!(close(d.scale,Vec3 { x: -2.0,y: 0.5,z: 3.0, }));
+        assert!(close(Mat4x4::from_trs(d.translation,d.rotation,d.scale),m));
+
+        // flattened along one axis, the rotation is rebuilt from the others
+        let d = Mat4x4::from_trs(t,rotation(),Vec3 { x: 2.0,y: 0.0,z: 3.0, }).decompose();
+        assert!(!d.negative);
+        assert!(close(d.scale,Vec3 { x: 2.0,y: 0.0,z: 3.0, }));
+        assert!(same_rotation(d.rotation,rotation()));
+    }
+
+    #[test]
+    fn look_at() {
+        let eye = Vec3 { x: 1.0,y: 2.0,z: 3.0, };
+        let target = Vec3 { x: 4.0,y: -1.0,z: 5.0, };
+        let up = Vec3 { x: 0.0,y: 1.0,z: 0.0, };
+        let distance = (target - eye).length();
+        for (handedness,view) in [(Handedness::Right,-1.0),(Handedness::Left,1.0)] {
+            let m = Mat4x4::look_at(eye,target,up,handedness);
+            assert!(close(m,Mat4x4::look_to(eye,(target - eye) * 2.0,up,handedness)));
+            assert!(close(m.linear().transpose() * m.linear(),Mat3x3::IDENTITY));
+            assert!((m.determinant() - 1.0).abs() < 1e-12);
+
+            // the eye ends up in the origin, the target straight ahead
+            assert!(close(m * eye.to_homogeneous_point(),Vec4 { x: 0.0,y: 0.0,z: 0.0,w: 1.0, }));
+            assert!(close(m * target.to_homogeneous_point(),Vec4 { x: 0.0,y: 0.0,z: view * distance,w: 1.0, }));
+
+            // looking straight down still gives a valid view matrix
+            let m = Mat4x4::look_to(eye,Vec3 { x: 0.0,y: -2.0,z: 0.0, },up,handedness);
+            assert!(close(m.linear().transpose() * m.linear(),Mat3x3::IDENTITY));
+            assert!((m.determinant() - 1.0).abs() < 1e-12);
+            let below = Vec4 { x: eye.x,y: eye.y - 2.0,z: eye.z,w: 1.0, };
+            assert!(close(m * below,Vec4 { x: 0.0,y: 0.0,z: view * 2.0,w: 1.0, }));
+        }
+    }
+}
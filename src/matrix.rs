@@ -1 +1,397 @@
-// TODO: generic matrix
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        error::Error,
+        ops::{
+            Add,
+            Sub,
+            Mul,
+            Div,
+            AddAssign,
+            SubAssign,
+            MulAssign,
+            DivAssign,
+            Neg,
+            Index,
+            IndexMut,
+        },
+    },
+};
+
+/// Error returned when a matrix is singular (or too close to singular) to be inverted.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub struct SingularMatrixError;
+
+impl Display for SingularMatrixError {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"matrix is singular")
+    }
+}
+
+impl Error for SingularMatrixError { }
+
+/// RxC matrix template.
+///
+/// For sizes that have no dedicated type (like [`Mat2x2`], [`Mat3x3`] or [`Mat4x4`]), e.g. covariance matrices in
+/// Kalman filters. Like the dedicated types, the matrix is stored as `C` columns of `R` elements each, so `m.0[c][r]` is
+/// the element in row `r` of column `c`. Elements can also be indexed as `m[(r,c)]`. The sizes are part of the type, so
+/// multiplying matrices with mismatched shapes does not compile.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as integers, [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct Matrix<T,const R: usize,const C: usize>(pub [[T; R]; C]);
+
+impl<T: Copy + Zero,const R: usize,const C: usize> Matrix<T,R,C> {
+
+    /// matrix with all elements 0.
+    pub const ZERO: Self = Matrix([[T::ZERO; R]; C]);
+}
+
+impl<T: Copy + Zero + One,const N: usize> Matrix<T,N,N> {
+
+    /// identity matrix.
+    pub const IDENTITY: Self = {
+        let mut m = [[T::ZERO; N]; N];
+        let mut i = 0;
+        while i < N {
+            m[i][i] = T::ONE;
+            i += 1;
+        }
+        Matrix(m)
+    };
+}
+
+impl<T: Copy,const R: usize,const C: usize> Matrix<T,R,C> {
+
+    /// build a matrix from `f(row,col)` for each element.
+    pub fn from_fn<F: FnMut(usize,usize) -> T>(mut f: F) -> Self {
+        Matrix(std::array::from_fn(|c| std::array::from_fn(|r| f(r,c))))
+    }
+
+    /// apply `f` to each element.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Matrix<U,R,C> {
+        Matrix(self.0.map(|column| column.map(&mut f)))
+    }
+
+    /// transpose.
+    pub fn transpose(self) -> Matrix<T,C,R> {
+        Matrix::from_fn(|r,c| self.0[r][c])
+    }
+}
+
+/// Display the matrix as `[(column),(column),...]`.
+impl<T: Display,const R: usize,const C: usize> Display for Matrix<T,R,C> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"[")?;
+        for (c,column) in self.0.iter().enumerate() {
+            if c > 0 {
+                write!(f,",")?;
+            }
+            write!(f,"(")?;
+            for (r,e) in column.iter().enumerate() {
+                if r > 0 {
+                    write!(f,",")?;
+                }
+                write!(f,"{}",e)?;
+            }
+            write!(f,")")?;
+        }
+        write!(f,"]")
+    }
+}
+
+impl<T,const R: usize,const C: usize> Index<(usize,usize)> for Matrix<T,R,C> {
+    type Output = T;
+    fn index(&self,(row,col): (usize,usize)) -> &T {
+        &self.0[col][row]
+    }
+}
+
+impl<T,const R: usize,const C: usize> IndexMut<(usize,usize)> for Matrix<T,R,C> {
+    fn index_mut(&mut self,(row,col): (usize,usize)) -> &mut T {
+        &mut self.0[col][row]
+    }
+}
+
+impl<T,const R: usize,const C: usize> From<[[T; R]; C]> for Matrix<T,R,C> {
+    fn from(array: [[T; R]; C]) -> Self {
+        Matrix(array)
+    }
+}
+
+impl<T,const R: usize,const C: usize> From<Matrix<T,R,C>> for [[T; R]; C] {
+    fn from(matrix: Matrix<T,R,C>) -> Self {
+        matrix.0
+    }
+}
+
+impl<T> From<Mat2x2<T>> for Matrix<T,2,2> {
+    fn from(matrix: Mat2x2<T>) -> Self {
+        Matrix([
+            [matrix.x.x,matrix.x.y],
+            [matrix.y.x,matrix.y.y],
+        ])
+    }
+}
+
+impl<T> From<Matrix<T,2,2>> for Mat2x2<T> {
+    fn from(matrix: Matrix<T,2,2>) -> Self {
+        let [x,y] = matrix.0;
+        Mat2x2 {
+            x: Vec2::from(x),
+            y: Vec2::from(y),
+        }
+    }
+}
+
+impl<T> From<Mat3x3<T>> for Matrix<T,3,3> {
+    fn from(matrix: Mat3x3<T>) -> Self {
+        Matrix([
+            [matrix.x.x,matrix.x.y,matrix.x.z],
+            [matrix.y.x,matrix.y.y,matrix.y.z],
+            [matrix.z.x,matrix.z.y,matrix.z.z],
+        ])
+    }
+}
+
+impl<T> From<Matrix<T,3,3>> for Mat3x3<T> {
+    fn from(matrix: Matrix<T,3,3>) -> Self {
+        let [x,y,z] = matrix.0;
+        Mat3x3 {
+            x: Vec3::from(x),
+            y: Vec3::from(y),
+            z: Vec3::from(z),
+        }
+    }
+}
+
+impl<T> From<Mat4x4<T>> for Matrix<T,4,4> {
+    fn from(matrix: Mat4x4<T>) -> Self {
+        Matrix([
+            [matrix.x.x,matrix.x.y,matrix.x.z,matrix.x.w],
+            [matrix.y.x,matrix.y.y,matrix.y.z,matrix.y.w],
+            [matrix.z.x,matrix.z.y,matrix.z.z,matrix.z.w],
+            [matrix.w.x,matrix.w.y,matrix.w.z,matrix.w.w],
+        ])
+    }
+}
+
+impl<T> From<Matrix<T,4,4>> for Mat4x4<T> {
+    fn from(matrix: Matrix<T,4,4>) -> Self {
+        let [x,y,z,w] = matrix.0;
+        Mat4x4 {
+            x: Vec4::from(x),
+            y: Vec4::from(y),
+            z: Vec4::from(z),
+            w: Vec4::from(w),
+        }
+    }
+}
+
+// matrix == matrix
+impl<T: PartialEq,const R: usize,const C: usize> PartialEq<Matrix<T,R,C>> for Matrix<T,R,C> {
+    fn eq(&self,other: &Matrix<T,R,C>) -> bool {
+        self.0 == other.0
+    }
+}
+
+// matrix + matrix
+impl<T: Copy + Add<Output=T>,const R: usize,const C: usize> Add<Matrix<T,R,C>> for Matrix<T,R,C> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self {
+        Matrix::from_fn(|r,c| self.0[c][r] + other.0[c][r])
+    }
+}
+
+// matrix += matrix
+impl<T: Copy + AddAssign,const R: usize,const C: usize> AddAssign<Matrix<T,R,C>> for Matrix<T,R,C> {
+    fn add_assign(&mut self,other: Self) {
+        for (column,o) in self.0.iter_mut().zip(other.0) {
+            for (e,o) in column.iter_mut().zip(o) {
+                *e += o;
+            }
+        }
+    }
+}
+
+// matrix - matrix
+impl<T: Copy + Sub<Output=T>,const R: usize,const C: usize> Sub<Matrix<T,R,C>> for Matrix<T,R,C> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self {
+        Matrix::from_fn(|r,c| self.0[c][r] - other.0[c][r])
+    }
+}
+
+// matrix -= matrix
+impl<T: Copy + SubAssign,const R: usize,const C: usize> SubAssign<Matrix<T,R,C>> for Matrix<T,R,C> {
+    fn sub_assign(&mut self,other: Self) {
+        for (column,o) in self.0.iter_mut().zip(other.0) {
+            for (e,o) in column.iter_mut().zip(o) {
+                *e -= o;
+            }
+        }
+    }
+}
+
+// scalar * matrix
+macro_rules! scalar_matrix_mul {
+    ($($t:ty)+) => {
+        $(
+            impl<const R: usize,const C: usize> Mul<Matrix<$t,R,C>> for $t {
+                type Output = Matrix<$t,R,C>;
+                fn mul(self,other: Matrix<$t,R,C>) -> Matrix<$t,R,C> {
+                    other.map(|e| self * e)
+                }
+            }
+        )+
+    }
+}
+
+scalar_matrix_mul!(usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64);
+
+// matrix * scalar
+impl<T: Copy + Mul<Output=T>,const R: usize,const C: usize> Mul<T> for Matrix<T,R,C> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self {
+        self.map(|e| e * other)
+    }
+}
+
+// matrix * vector
+impl<T: Copy + Zero + Add<Output=T> + Mul<Output=T>,const R: usize,const C: usize> Mul<VecN<T,C>> for Matrix<T,R,C> {
+    type Output = VecN<T,R>;
+    fn mul(self,other: VecN<T,C>) -> VecN<T,R> {
+        VecN(std::array::from_fn(|r| {
+            let mut sum = T::ZERO;
+            for c in 0..C {
+                sum = sum + self.0[c][r] * other.0[c];
+            }
+            sum
+        }))
+    }
+}
+
+// matrix * matrix
+impl<T: Copy + Zero + Add<Output=T> + Mul<Output=T>,const R: usize,const K: usize,const C: usize> Mul<Matrix<T,K,C>> for Matrix<T,R,K> {
+    type Output = Matrix<T,R,C>;
+    fn mul(self,other: Matrix<T,K,C>) -> Matrix<T,R,C> {
+        Matrix::from_fn(|r,c| {
+            let mut sum = T::ZERO;
+            for k in 0..K {
+                sum = sum + self.0[k][r] * other.0[c][k];
+            }
+            sum
+        })
+    }
+}
+
+// matrix *= scalar
+impl<T: Copy + MulAssign,const R: usize,const C: usize> MulAssign<T> for Matrix<T,R,C> {
+    fn mul_assign(&mut self,other: T) {
+        for column in self.0.iter_mut() {
+            for e in column.iter_mut() {
+                *e *= other;
+            }
+        }
+    }
+}
+
+// matrix *= matrix
+impl<T: Copy + Zero + Add<Output=T> + Mul<Output=T>,const N: usize> MulAssign<Matrix<T,N,N>> for Matrix<T,N,N> {
+    fn mul_assign(&mut self,other: Matrix<T,N,N>) {
+        *self = *self * other;
+    }
+}
+
+// matrix / scalar
+impl<T: Copy + Div<Output=T>,const R: usize,const C: usize> Div<T> for Matrix<T,R,C> {
+    type Output = Self;
+    fn div(self,other: T) -> Self {
+        self.map(|e| e / other)
+    }
+}
+
+// matrix /= scalar
+impl<T: Copy + DivAssign,const R: usize,const C: usize> DivAssign<T> for Matrix<T,R,C> {
+    fn div_assign(&mut self,other: T) {
+        for column in self.0.iter_mut() {
+            for e in column.iter_mut() {
+                *e /= other;
+            }
+        }
+    }
+}
+
+// -matrix
+impl<T: Copy + Neg<Output=T>,const R: usize,const C: usize> Neg for Matrix<T,R,C> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        self.map(|e| -e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // rows [1 2 3] and [4 5 6]
+    fn a() -> Matrix<i32,2,3> {
+        Matrix([[1,4],[2,5],[3,6]])
+    }
+
+    #[test]
+    fn identity() {
+        assert!(Matrix::<i32,3,3>::IDENTITY == Matrix([[1,0,0],[0,1,0],[0,0,1]]));
+        assert!(Matrix::<i32,2,3>::ZERO == Matrix([[0,0],[0,0],[0,0]]));
+        assert!(Matrix::IDENTITY * a() == a());
+        assert!(a() * Matrix::IDENTITY == a());
+    }
+
+    #[test]
+    fn index() {
+        assert!(Matrix::<i32,2,3>::from_fn(|r,c| (3 * r + c + 1) as i32) == a());
+        let mut a = a();
+        assert_eq!(a[(0,2)],3);
+        assert_eq!(a[(1,0)],4);
+        assert_eq!(a.0[2][0],3);
+        a[(1,2)] = -6;
+        assert_eq!(a.0[2][1],-6);
+    }
+
+    #[test]
+    fn transpose() {
+        let t: Matrix<i32,3,2> = a().transpose();
+        assert!(t == Matrix([[1,2,3],[4,5,6]]));
+        assert_eq!(t[(2,1)],a()[(1,2)]);
+        assert!(t.transpose() == a());
+    }
+
+    #[test]
+    fn product() {
+        let b = Matrix::<i32,3,4>([[1,0,2],[0,1,1],[-1,2,0],[3,0,1]]);
+        let p: Matrix<i32,2,4> = a() * b;
+        assert!(p == Matrix([[7,16],[5,11],[3,6],[6,18]]));
+        assert!(a() * VecN([1,0,2]) == VecN([7,16]));
+        assert!((a() * b).transpose() == b.transpose() * a().transpose());
+    }
+
+    #[test]
+    fn dedicated_types() {
+        let m = Mat2x2::from([1,2,3,4]);
+        assert!(Matrix::from(m) == Matrix([[1,2],[3,4]]));
+        assert!(Mat2x2::from(Matrix::from(m)) == m);
+        let m = Mat3x3::from([1,2,3,4,5,6,7,8,9]);
+        assert_eq!(Matrix::from(m)[(0,1)],m.y.x);
+        assert!(Mat3x3::from(Matrix::from(m)) == m);
+        let m = Mat4x4::from([1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16]);
+        assert_eq!(Matrix::from(m)[(3,2)],m.z.w);
+        assert!(Mat4x4::from(Matrix::from(m)) == m);
+        assert!(Mat4x4::from(Matrix::<i32,4,4>::IDENTITY) == Mat4x4::IDENTITY);
+    }
+}
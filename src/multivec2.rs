@@ -33,3 +33,14 @@ pub struct MultiVec2<T> {
     pub y: T,
     pub xy: T,
 }
+
+impl<T: Copy> MultiVec2<T> {
+
+    /// return grade-1 (vector) part, which can be used with the [`Vector`] trait.
+    pub fn vector(&self) -> Vec2<T> {
+        Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
@@ -43,3 +43,15 @@ pub struct MultiVec3<T> {
     pub yz: T,
     pub xyz: T, // imaginary number, magnetic flux, etc.
 }
+
+impl<T: Copy> MultiVec3<T> {
+
+    /// return grade-1 (vector) part, which can be used with the [`Vector`] trait.
+    pub fn vector(&self) -> Vec3<T> {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
@@ -46,3 +46,16 @@ pub struct MultiVec4<T> {
     pub yzw: T,
     pub xyzw: T,
 }
+
+impl<T: Copy> MultiVec4<T> {
+
+    /// return grade-1 (vector) part, which can be used with the [`Vector`] trait.
+    pub fn vector(&self) -> Vec4<T> {
+        Vec4 {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: self.w,
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use {
+    crate::*,
+    std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+    },
+};
+
+/// QR decomposition by Householder reflections.
+///
+/// Factors an RxC matrix `A` with at least as many rows as columns as `A = Q * R`, where `Q` is an RxC matrix with
+/// orthonormal columns and `R` is a CxC upper triangular matrix. For a tall matrix this solves over-determined systems in
+/// the least-squares sense, without the loss of precision that comes with forming the normal equations.
+///
+/// Create one with [`Matrix::qr`], [`Mat3x3::qr`] or [`Mat4x4::qr`].
+#[derive(Copy,Clone,Debug)]
+pub struct Qr<T,const R: usize,const C: usize> {
+    /// Householder vectors on and below the diagonal, `R` above it.
+    qr: Matrix<T,R,C>,
+    /// diagonal of `R`.
+    diagonal: [T; C],
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real,const R: usize,const C: usize> Qr<T,R,C> {
+
+    /// factor `matrix`.
+    pub fn new(matrix: Matrix<T,R,C>) -> Self {
+        const { assert!(R >= C,"QR decomposition needs at least as many rows as columns") };
+        let mut qr = matrix;
+        let mut diagonal = [T::ZERO; C];
+        for k in 0..C {
+
+            // length of the column below the diagonal
+            let mut norm = T::ZERO;
+            for i in k..R {
+                norm = norm.hypot(qr[(i,k)]);
+            }
+
+            if norm != T::ZERO {
+
+                // Householder vector for this column
+                if qr[(k,k)] < T::ZERO {
+                    norm = -norm;
+                }
+                for i in k..R {
+                    qr[(i,k)] = qr[(i,k)] / norm;
+                }
+                qr[(k,k)] = qr[(k,k)] + T::ONE;
+
+                // reflect the remaining columns
+                for j in k + 1..C {
+                    let mut s = T::ZERO;
+                    for i in k..R {
+                        s = s + qr[(i,k)] * qr[(i,j)];
+                    }
+                    s = -s / qr[(k,k)];
+                    for i in k..R {
+                        qr[(i,j)] = qr[(i,j)] + s * qr[(i,k)];
+                    }
+                }
+            }
+            diagonal[k] = -norm;
+        }
+        Qr { qr,diagonal, }
+    }
+
+    /// whether the factored matrix has full column rank.
+    pub fn is_full_rank(&self) -> bool {
+        self.diagonal.iter().all(|d| *d != T::ZERO)
+    }
+
+    /// orthonormal factor `Q`, with one column for each column of the factored matrix.
+    pub fn q(&self) -> Matrix<T,R,C> {
+        let mut q = Matrix::<T,R,C>::ZERO;
+        for k in (0..C).rev() {
+            q[(k,k)] = T::ONE;
+            for j in k..C {
+                if self.qr[(k,k)] != T::ZERO {
+                    let mut s = T::ZERO;
+                    for i in k..R {
+                        s = s + self.qr[(i,k)] * q[(i,j)];
+                    }
+                    s = -s / self.qr[(k,k)];
+                    for i in k..R {
+                        q[(i,j)] = q[(i,j)] + s * self.qr[(i,k)];
+                    }
+                }
+            }
+        }
+        q
+    }
+
+    /// upper triangular factor `R`.
+    pub fn r(&self) -> Matrix<T,C,C> {
+        Matrix::from_fn(|r,c| if r < c { self.qr[(r,c)] } else if r == c { self.diagonal[r] } else { T::ZERO })
+    }
+
+    /// find `x` that minimizes the length of `A * x - b`, or `None` if the matrix does not have full column rank.
+    ///
+    /// For a square matrix this is the exact solution of `A * x = b`.
+    pub fn solve_least_squares(&self,b: VecN<T,R>) -> Option<VecN<T,C>> {
+        if !self.is_full_rank() {
+            return None;
+        }
+
+        // apply the reflections to b, which computes Q^T * b
+        let mut y = b.0;
+        for k in 0..C {
+            let mut s = T::ZERO;
+            for (i,y) in y.iter().enumerate().skip(k) {
+                s = s + self.qr[(i,k)] * *y;
+            }
+            s = -s / self.qr[(k,k)];
+            for (i,y) in y.iter_mut().enumerate().skip(k) {
+                *y = *y + s * self.qr[(i,k)];
+            }
+        }
+
+        // back substitution with R
+        let mut x: [T; C] = std::array::from_fn(|i| y[i]);
+        for k in (0..C).rev() {
+            x[k] = x[k] / self.diagonal[k];
+            for i in 0..k {
+                x[i] = x[i] - x[k] * self.qr[(i,k)];
+            }
+        }
+
+        Some(VecN(x))
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real,const R: usize,const C: usize> Matrix<T,R,C> {
+
+    /// QR decomposition by Householder reflections; the matrix must have at least as many rows as columns.
+    pub fn qr(self) -> Qr<T,R,C> {
+        Qr::new(self)
+    }
+
+    /// find `x` that minimizes the length of `self * x - b`, or `None` if the matrix does not have full column rank.
+    ///
+    /// To fit several right-hand sides against the same matrix, factor once with [`Matrix::qr`] instead.
+    pub fn solve_least_squares(self,b: VecN<T,R>) -> Option<VecN<T,C>> {
+        self.qr().solve_least_squares(b)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real> Mat3x3<T> {
+
+    /// QR decomposition by Householder reflections.
+    pub fn qr(self) -> Qr<T,3,3> {
+        Qr::new(self.into())
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real> Mat4x4<T> {
+
+    /// QR decomposition by Householder reflections.
+    pub fn qr(self) -> Qr<T,4,4> {
+        Qr::new(self.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn tall() -> Matrix<f64,5,3> {
+        Matrix::from_fn(|r,c| [[1.0,2.0,-1.0],[0.5,-3.0,2.0],[4.0,1.0,0.0],[-2.0,0.5,1.5],[1.0,1.0,1.0]][r][c])
+    }
+
+    #[test]
+    fn reconstruction() {
+        let a = tall();
+        let qr = a.qr();
+        assert!(qr.is_full_rank());
+        assert!(close(qr.q() * qr.r(),a));
+        assert!(close(qr.q().transpose() * qr.q(),Matrix::IDENTITY));
+    }
+
+    #[test]
+    fn least_squares_residual() {
+        let a = tall();
+        let b = VecN([1.0,-2.0,0.5,3.0,-1.0]);
+        let x = a.solve_least_squares(b).unwrap();
+        let residual = a * x - b;
+        let projected = a.transpose() * residual;
+        assert!(projected.0.iter().all(|e| e.abs() < 1e-12));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let a = Matrix::<f64,4,2>::from_fn(|r,c| if c == 0 { r as f64 + 1.0 } else { 0.0 });
+        let qr = a.qr();
+        assert!(!qr.is_full_rank());
+        assert!(qr.solve_least_squares(VecN([1.0,2.0,3.0,4.0])).is_none());
+    }
+}
@@ -79,6 +79,60 @@ impl<T: Copy + Add<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + F
     }
 }
 
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Real> From<Mat3x3<T>> for Quaternion<T> {
+    /// unit quaternion of a rotation matrix.
+    fn from(m: Mat3x3<T>) -> Self {
+        // pick the largest of r, i, j and k to divide by (Shepperd's method)
+        let two = T::ONE + T::ONE;
+        let trace = m.x.x + m.y.y + m.z.z;
+        if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * two;
+            Quaternion {
+                r: s / (two + two),
+                i: (m.y.z - m.z.y) / s,
+                j: (m.z.x - m.x.z) / s,
+                k: (m.x.y - m.y.x) / s,
+            }
+        }
+        else if (m.x.x > m.y.y) && (m.x.x > m.z.z) {
+            let s = (T::ONE + m.x.x - m.y.y - m.z.z).sqrt() * two;
+            Quaternion {
+                r: (m.y.z - m.z.y) / s,
+                i: s / (two + two),
+                j: (m.y.x + m.x.y) / s,
+                k: (m.z.x + m.x.z) / s,
+            }
+        }
+        else if m.y.y > m.z.z {
+            let s = (T::ONE + m.y.y - m.x.x - m.z.z).sqrt() * two;
+            Quaternion {
+                r: (m.z.x - m.x.z) / s,
+                i: (m.y.x + m.x.y) / s,
+                j: s / (two + two),
+                k: (m.z.y + m.y.z) / s,
+            }
+        }
+        else {
+            let s = (T::ONE + m.z.z - m.x.x - m.y.y).sqrt() * two;
+            Quaternion {
+                r: (m.x.y - m.y.x) / s,
+                i: (m.z.x + m.x.z) / s,
+                j: (m.z.y + m.y.z) / s,
+                k: s / (two + two),
+            }
+        }
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Quaternion<T> {
+
+    /// rotation that turns the viewing direction of `handedness` (-Z or +Z) towards `forward`, keeping +Y as close to
+    /// `up` as possible. See [`Mat3x3::look_rotation`].
+    pub fn look_rotation(forward: Vec3<T>,up: Vec3<T>,handedness: Handedness) -> Self {
+        Mat3x3::look_rotation(forward,up,handedness).into()
+    }
+}
+
 // quaternion == scalar
 impl<T: Zero + PartialEq> PartialEq<T> for Quaternion<T> {
     fn eq(&self,other: &T) -> bool {
@@ -580,3 +634,24 @@ impl<T: Neg<Output=T>> Neg for Quaternion<T> {
 pub type f32q = Quaternion<f32>;
 #[allow(non_camel_case_types)]
 pub type f64q = Quaternion<f64>;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    #[test]
+    fn look_rotation() {
+        let up = Vec3 { x: 0.0,y: 1.0,z: 0.0, };
+        for (handedness,view) in [(Handedness::Right,-1.0),(Handedness::Left,1.0)] {
+            for forward in [Vec3 { x: 1.0,y: -0.5,z: 2.0, },up * 3.0,-up] {
+                let q = Quaternion::look_rotation(forward,up,handedness);
+                assert!((q.norm() - 1.0).abs() < 1e-12);
+                assert!(close(Mat3x3::from(q),Mat3x3::look_rotation(forward,up,handedness)));
+                assert!(close(q * Vec3 { x: 0.0,y: 0.0,z: view, },forward.normalize()));
+            }
+        }
+    }
+}
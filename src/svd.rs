@@ -0,0 +1,181 @@
+use {
+    crate::*,
+    std::ops::{
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Neg,
+    },
+};
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat2x2<T> {
+
+    /// singular value decomposition.
+    ///
+    /// Returns `(u,s,v)` such that `self = u * Mat2x2::from_diagonal(s) * v.transpose()`, where `u` and `v` are orthonormal and
+    /// the singular values in `s` are non-negative, from largest to smallest.
+    pub fn svd(self) -> (Mat2x2<T>,Vec2<T>,Mat2x2<T>) {
+        // split into the parts that commute with rotations and reflections
+        let two = T::ONE + T::ONE;
+        let e = (self.x.x + self.y.y) / two;
+        let f = (self.x.x - self.y.y) / two;
+        let g = (self.x.y + self.y.x) / two;
+        let h = (self.x.y - self.y.x) / two;
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+
+        // self = from_angle(phi) * diag(q + r,q - r) * from_angle(theta)
+        let theta = (a2 - a1) / two;
+        let phi = (a2 + a1) / two;
+        let mut u = Mat2x2::from_angle(phi);
+        let v = Mat2x2::from_angle(-theta);
+        let mut s = Vec2 { x: q + r,y: q - r, };
+        if s.y < T::ZERO {
+            s.y = -s.y;
+            u.y = -u.y;
+        }
+        (u,s,v)
+    }
+
+    /// polar decomposition.
+    ///
+    /// Returns `(r,s)` such that `self = r * s`, where `r` is the rotation closest to `self` and `s` is symmetric. When
+    /// `self` contains a reflection, `s` has a negative eigenvalue.
+    pub fn polar(self) -> (Mat2x2<T>,Mat2x2<T>) {
+        let r = Mat2x2::from_angle((self.x.y - self.y.x).atan2(self.x.x + self.y.y));
+        (r,r.transpose() * self)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T> + Neg<Output=T> + Real + Float> Mat3x3<T> {
+
+    /// decomposition into two rotations and signed singular values.
+    ///
+    /// Like the singular value decomposition, but `u` and `v` are proper rotations, and the last singular value takes
+    /// the sign of the determinant instead.
+    fn signed_svd(self) -> (Mat3x3<T>,Vec3<T>,Mat3x3<T>) {
+        // v from the eigenvectors of self^T * self
+        let (_,mut v) = (self.transpose() * self).symmetric_eigen();
+        if v.determinant() < T::ZERO {
+            v.z = -v.z;
+        }
+
+        // u from the images of v, orthogonalized
+        let a1 = self * v.x;
+        let a2 = self * v.y;
+        let a3 = self * v.z;
+        let s1 = a1.length();
+        let tolerance = T::EPSILON * s1;
+        let u1 = if s1 > T::ZERO {
+            a1 / s1
+        }
+        else {
+            Vec3 { x: T::ONE,y: T::ZERO,z: T::ZERO, }
+        };
+        let b2 = a2 - u1 * u1.dot(a2);
+        let s2 = b2.length();
+        let u2 = if s2 > tolerance {
+            b2 / s2
+        }
+        else {
+            u1.any_perpendicular()
+        };
+        let u3 = u1.cross(u2);
+        let s3 = u3.dot(a3);
+
+        (Mat3x3 { x: u1,y: u2,z: u3, },Vec3 { x: s1,y: s2,z: s3, },v)
+    }
+
+    /// singular value decomposition.
+    ///
+    /// Returns `(u,s,v)` such that `self = u * diag(s) * v.transpose()`, where `u` and `v` are orthonormal and the
+    /// singular values in `s` are non-negative, from largest to smallest.
+    pub fn svd(self) -> (Mat3x3<T>,Vec3<T>,Mat3x3<T>) {
+        let (mut u,mut s,v) = self.signed_svd();
+        if s.z < T::ZERO {
+            s.z = -s.z;
+            u.z = -u.z;
+        }
+        (u,s,v)
+    }
+
+    /// polar decomposition.
+    ///
+    /// Returns `(r,s)` such that `self = r * s`, where `r` is the rotation closest to `self` and `s` is symmetric. When
+    /// `self` contains a reflection (like an inverted finite element), `r` stays a proper rotation and `s` gets a
+    /// negative eigenvalue.
+    pub fn polar(self) -> (Mat3x3<T>,Mat3x3<T>) {
+        let (u,s,v) = self.signed_svd();
+        let vt = v.transpose();
+        (u * vt,v * Mat3x3::from_diagonal(s) * vt)
+    }
+
+    /// polar decomposition, with the rotation as a [`Quaternion`].
+    pub fn polar_quaternion(self) -> (Quaternion<T>,Mat3x3<T>) {
+        let (r,s) = self.polar();
+        (r.into(),s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::testing::*,
+    };
+
+    fn check_svd(a: Mat3x3<f64>) {
+        let (u,s,v) = a.svd();
+        assert!(close(u * Mat3x3::from_diagonal(s) * v.transpose(),a));
+        assert!(close(u.transpose() * u,Mat3x3::IDENTITY));
+        assert!(close(v.transpose() * v,Mat3x3::IDENTITY));
+        assert!((s.x >= s.y) && (s.y >= s.z) && (s.z >= 0.0));
+    }
+
+    #[test]
+    fn reconstruction() {
+        check_svd(Mat3x3::from([2.0,0.5,-1.0,1.0,3.0,0.0,-0.5,1.0,4.0]));
+
+        // with a reflection
+        check_svd(Mat3x3::from([0.0,1.0,0.0,1.0,0.0,0.0,0.0,0.0,2.0]));
+
+        let a = Mat2x2::from([1.0,2.0,3.0,4.0]);
+        let (u,s,v) = a.svd();
+        assert!(close(u * Mat2x2::from_diagonal(s) * v.transpose(),a));
+        assert!(s.x >= s.y && s.y >= 0.0);
+    }
+
+    #[test]
+    fn rank_deficient() {
+        // rank 1
+        let u = Vec3 { x: 1.0,y: 2.0,z: -1.0, };
+        let v = Vec3 { x: 0.5,y: -1.0,z: 3.0, };
+        let a = Mat3x3 { x: u * v.x,y: u * v.y,z: u * v.z, };
+        check_svd(a);
+        let (_,s,_) = a.svd();
+        assert!((s.x - u.length() * v.length()).abs() < 1e-12);
+        assert!(s.y.abs() < 1e-12 && s.z.abs() < 1e-12);
+        check_svd(Mat3x3::ZERO);
+    }
+
+    #[test]
+    fn polar() {
+        for a in [
+            Mat3x3::from([2.0,0.5,-1.0,1.0,3.0,0.0,-0.5,1.0,4.0]),
+            Mat3x3::from([0.0,1.0,0.0,1.0,0.0,0.0,0.0,0.0,2.0]),
+        ] {
+            let (r,s) = a.polar();
+            assert!(close(r * s,a));
+            assert!(close(r.transpose() * r,Mat3x3::IDENTITY));
+            assert!((r.determinant() - 1.0).abs() < 1e-12);
+            assert!(close(s,s.transpose()));
+        }
+        let a = Mat2x2::from([0.0,1.0,1.0,0.0]);
+        let (r,s) = a.polar();
+        assert!((r.determinant() - 1.0).abs() < 1e-12);
+        assert!(close(r * s,a));
+    }
+}
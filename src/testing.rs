@@ -0,0 +1,67 @@
+use crate::*;
+
+/// default tolerance of [`close`].
+pub(crate) const TOLERANCE: f64 = 1e-12;
+
+/// elements of a vector or matrix, to compare them one by one.
+pub(crate) trait Elements {
+    fn elements(&self) -> Vec<f64>;
+}
+
+impl Elements for Vec2<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.as_ref().to_vec()
+    }
+}
+
+impl Elements for Vec3<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.as_ref().to_vec()
+    }
+}
+
+impl Elements for Vec4<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.as_ref().to_vec()
+    }
+}
+
+impl<const N: usize> Elements for VecN<f64,N> {
+    fn elements(&self) -> Vec<f64> {
+        self.0.to_vec()
+    }
+}
+
+impl Elements for Mat2x2<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.iter().copied().collect()
+    }
+}
+
+impl Elements for Mat3x3<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.iter().copied().collect()
+    }
+}
+
+impl Elements for Mat4x4<f64> {
+    fn elements(&self) -> Vec<f64> {
+        self.iter().copied().collect()
+    }
+}
+
+impl<const R: usize,const C: usize> Elements for Matrix<f64,R,C> {
+    fn elements(&self) -> Vec<f64> {
+        self.0.iter().flatten().copied().collect()
+    }
+}
+
+/// whether all elements of `a` and `b` differ less than `tolerance`.
+pub(crate) fn close_within<E: Elements>(a: E,b: E,tolerance: f64) -> bool {
+    a.elements().iter().zip(b.elements().iter()).all(|(a,b)| (a - b).abs() < tolerance)
+}
+
+/// whether all elements of `a` and `b` differ less than [`TOLERANCE`].
+pub(crate) fn close<E: Elements>(a: E,b: E) -> bool {
+    close_within(a,b,TOLERANCE)
+}
@@ -1,5 +1,553 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+    },
+};
+
+/// 2D vector template.
+///
+/// A 2D vector describes a position, direction or displacement in the plane, with components `x` and `y`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as integers, [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
+#[repr(C)]
 pub struct Vec2<T> {
     pub x: T,
     pub y: T,
-}
\ No newline at end of file
+}
+
+/// Display the vector as `(x,y)`.
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{})",self.x,self.y)
+    }
+}
+
+impl<T: Copy> Vec2<T> {
+
+    /// return copy with `x` replaced.
+    pub fn with_x(self,x: T) -> Self {
+        Vec2 { x,y: self.y, }
+    }
+
+    /// return copy with `y` replaced.
+    pub fn with_y(self,y: T) -> Self {
+        Vec2 { x: self.x,y, }
+    }
+
+    /// extend to 3D vector.
+    pub fn extend(self,z: T) -> Vec3<T> {
+        Vec3 { x: self.x,y: self.y,z, }
+    }
+
+    /// apply `f` to each component.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Vec2<U> {
+        Vec2 { x: f(self.x),y: f(self.y), }
+    }
+
+    /// apply `f` to each pair of components of `self` and `other`.
+    pub fn zip_map<U,V,F: FnMut(T,U) -> V>(self,other: Vec2<U>,mut f: F) -> Vec2<V> {
+        Vec2 { x: f(self.x,other.x),y: f(self.y,other.y), }
+    }
+
+    // swizzles
+    swizzle_vec2! {
+        xx: x x, xy: x y, yx: y x, yy: y y
+    }
+
+    swizzle_vec3! {
+        xxx: x x x, xxy: x x y, xyx: x y x, xyy: x y y, yxx: y x x, yxy: y x y, yyx: y y x, yyy: y y y
+    }
+
+    swizzle_vec4! {
+        xxxx: x x x x, xxxy: x x x y, xxyx: x x y x, xxyy: x x y y, xyxx: x y x x, xyxy: x y x y, xyyx: x y y x,
+        xyyy: x y y y, yxxx: y x x x, yxxy: y x x y, yxyx: y x y x, yxyy: y x y y, yyxx: y y x x, yyxy: y y x y,
+        yyyx: y y y x, yyyy: y y y y
+    }
+}
+
+impl<T: Copy + Zero + One> Vec2<T> {
+
+    /// homogeneous coordinates of this point (`z` = 1), affected by translation.
+    pub fn to_homogeneous_point(self) -> Vec3<T> {
+        Vec3 { x: self.x,y: self.y,z: T::ONE, }
+    }
+
+    /// homogeneous coordinates of this direction (`z` = 0), not affected by translation.
+    pub fn to_homogeneous_vector(self) -> Vec3<T> {
+        Vec3 { x: self.x,y: self.y,z: T::ZERO, }
+    }
+}
+
+impl<T: Copy + Real> Vec2<T> {
+
+    /// component-wise minimum.
+    pub fn min(self,other: Self) -> Self {
+        Vec2 { x: self.x.min(other.x),y: self.y.min(other.y), }
+    }
+
+    /// component-wise maximum.
+    pub fn max(self,other: Self) -> Self {
+        Vec2 { x: self.x.max(other.x),y: self.y.max(other.y), }
+    }
+
+    /// component-wise clamp between `min` and `max`.
+    pub fn clamp(self,min: Self,max: Self) -> Self {
+        Vec2 { x: self.x.clamp(min.x,max.x),y: self.y.clamp(min.y,max.y), }
+    }
+
+    /// component-wise absolute value.
+    pub fn abs(self) -> Self {
+        self.map(|c| c.abs())
+    }
+
+    /// component-wise floor.
+    pub fn floor(self) -> Self {
+        self.map(|c| c.floor())
+    }
+
+    /// component-wise ceiling.
+    pub fn ceil(self) -> Self {
+        self.map(|c| c.ceil())
+    }
+
+    /// component-wise rounding.
+    pub fn round(self) -> Self {
+        self.map(|c| c.round())
+    }
+
+    /// component-wise sign.
+    pub fn signum(self) -> Self {
+        self.map(|c| c.signum())
+    }
+}
+
+impl<T: Copy + Add<Output=T>> Vec2<T> {
+
+    /// sum of the components.
+    pub fn sum(self) -> T {
+        self.x + self.y
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Vec2<T> {
+
+    /// product of the components.
+    pub fn product(self) -> T {
+        self.x * self.y
+    }
+}
+
+impl<T: Copy + PartialOrd> Vec2<T> {
+
+    /// smallest component.
+    pub fn min_element(self) -> T {
+        let mut result = self.x;
+        if self.y < result {
+            result = self.y;
+        }
+        result
+    }
+
+    /// largest component.
+    pub fn max_element(self) -> T {
+        let mut result = self.x;
+        if self.y > result {
+            result = self.y;
+        }
+        result
+    }
+
+    /// index of the largest component (the first one if there are several).
+    pub fn argmax(self) -> usize {
+        if self.y > self.x {
+            1
+        }
+        else {
+            0
+        }
+    }
+}
+
+impl<T: Copy + Unsigned> Vec2<T> {
+
+    /// component-wise Euclidean division.
+    pub fn div_euclid(self,rhs: Self) -> Self {
+        Vec2 { x: self.x.div_euclid(rhs.x),y: self.y.div_euclid(rhs.y), }
+    }
+
+    /// component-wise Euclidean remainder, always non-negative.
+    pub fn rem_euclid(self,rhs: Self) -> Self {
+        Vec2 { x: self.x.rem_euclid(rhs.x),y: self.y.rem_euclid(rhs.y), }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output=T> + Sub<Output=T>> Vec2<T> {
+
+    // component-wise absolute difference, also works for unsigned integers
+    fn abs_diff(self,other: Self) -> Self {
+        Vec2 { x: if self.x > other.x { self.x - other.x } else { other.x - self.x },y: if self.y > other.y { self.y - other.y } else { other.y - self.y }, }
+    }
+
+    /// Manhattan (taxicab) distance.
+    pub fn manhattan_distance(self,other: Self) -> T {
+        let d = self.abs_diff(other);
+        d.x + d.y
+    }
+
+    /// Chebyshev (chessboard) distance.
+    pub fn chebyshev_distance(self,other: Self) -> T {
+        let d = self.abs_diff(other);
+        let mut result = d.x;
+        if d.y > result {
+            result = d.y;
+        }
+        result
+    }
+}
+
+impl<T: Copy + One + PartialEq + Unsigned + Add<Output=T> + Sub<Output=T>> Vec2<T> {
+
+    /// the 4 edge-connected neighbors on an integer grid, skipping the ones outside the range of `T`.
+    pub fn neighbors4(self) -> impl Iterator<Item=Self> {
+        let [x0,x1,x2] = grid_steps(self.x);
+        let [y0,y1,y2] = grid_steps(self.y);
+        IntoIterator::into_iter([
+            (x0,y1),
+            (x2,y1),
+            (x1,y0),
+            (x1,y2),
+        ]).filter_map(|(x,y)| Some(Vec2 { x: x?,y: y?, }))
+    }
+
+    /// the 8 edge- and corner-connected neighbors on an integer grid, skipping the ones outside the range of `T`.
+    pub fn neighbors8(self) -> impl Iterator<Item=Self> {
+        let [x0,x1,x2] = grid_steps(self.x);
+        let [y0,y1,y2] = grid_steps(self.y);
+        IntoIterator::into_iter([
+            (x0,y0),
+            (x1,y0),
+            (x2,y0),
+            (x0,y1),
+            (x2,y1),
+            (x0,y2),
+            (x1,y2),
+            (x2,y2),
+        ]).filter_map(|(x,y)| Some(Vec2 { x: x?,y: y?, }))
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from(array: [T; 2]) -> Self {
+        let [x,y] = array;
+        Vec2 { x,y, }
+    }
+}
+
+impl<T: Copy> From<&[T; 2]> for Vec2<T> {
+    fn from(slice: &[T; 2]) -> Self {
+        Vec2 { x: slice[0],y: slice[1], }
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    fn from(vector: Vec2<T>) -> Self {
+        [vector.x,vector.y]
+    }
+}
+
+impl<T> From<(T,T)> for Vec2<T> {
+    fn from(tuple: (T,T)) -> Self {
+        Vec2 { x: tuple.0,y: tuple.1, }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T,T) {
+    fn from(vector: Vec2<T>) -> Self {
+        (vector.x,vector.y)
+    }
+}
+
+// the components are laid out contiguously because of #[repr(C)]
+impl<T> AsRef<[T]> for Vec2<T> {
+    fn as_ref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const T,2) }
+    }
+}
+
+impl<T> AsMut<[T]> for Vec2<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T,2) }
+    }
+}
+
+// vector == vector
+impl<T: PartialEq> PartialEq<Vec2<T>> for Vec2<T> {
+    fn eq(&self,other: &Vec2<T>) -> bool {
+        (self.x == other.x) &&
+        (self.y == other.y)
+    }
+}
+
+// scalar * vector
+macro_rules! scalar_vec2 {
+    ($($t:ty)*) => ($(
+        impl Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+            fn mul(self,other: Vec2<$t>) -> Vec2<$t> {
+                Vec2 {
+                    x: self * other.x,
+                    y: self * other.y,
+                }
+            }
+        }
+    )*)
+}
+
+scalar_vec2! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+// integer vector <-> float vector
+macro_rules! int_vec2 {
+    ($($t:ty)*) => ($(
+        impl Vec2<$t> {
+
+            /// convert from `f32` vector, using `rounding`.
+            pub fn from_f32(v: Vec2<f32>,rounding: Rounding) -> Self {
+                Vec2 { x: rounding.apply(v.x) as $t,y: rounding.apply(v.y) as $t, }
+            }
+
+            /// convert from `f64` vector, using `rounding`.
+            pub fn from_f64(v: Vec2<f64>,rounding: Rounding) -> Self {
+                Vec2 { x: rounding.apply(v.x) as $t,y: rounding.apply(v.y) as $t, }
+            }
+
+            /// convert to `f32` vector.
+            pub fn to_f32(self) -> Vec2<f32> {
+                Vec2 { x: self.x as f32,y: self.y as f32, }
+            }
+
+            /// convert to `f64` vector.
+            pub fn to_f64(self) -> Vec2<f64> {
+                Vec2 { x: self.x as f64,y: self.y as f64, }
+            }
+        }
+    )*)
+}
+
+int_vec2! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+// rational * vector
+macro_rules! rational_vec2 {
+    ($(($t:ty,$ut:ty))*) => ($(
+        impl Mul<Vec2<Rational<$t,$ut>>> for Rational<$t,$ut> {
+            type Output = Vec2<Rational<$t,$ut>>;
+            fn mul(self,other: Vec2<Rational<$t,$ut>>) -> Vec2<Rational<$t,$ut>> {
+                Vec2 {
+                    x: self * other.x,
+                    y: self * other.y,
+                }
+            }
+        }
+    )*)
+}
+
+rational_vec2! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+
+// fixed * vector
+impl<T,const B: usize> Mul<Vec2<Fixed<T,B>>> for Fixed<T,B> where Fixed<T,B>: Copy + Mul<Output=Fixed<T,B>> {
+    type Output = Vec2<Fixed<T,B>>;
+    fn mul(self,other: Vec2<Fixed<T,B>>) -> Vec2<Fixed<T,B>> {
+        Vec2 {
+            x: self * other.x,
+            y: self * other.y,
+        }
+    }
+}
+
+// vector + vector
+impl<T: Add<Output=T>> Add<Vec2<T>> for Vec2<T> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self::Output {
+        Vec2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+// vector += vector
+impl<T: AddAssign> AddAssign<Vec2<T>> for Vec2<T> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+// vector - vector
+impl<T: Sub<Output=T>> Sub<Vec2<T>> for Vec2<T> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self::Output {
+        Vec2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+// vector -= vector
+impl<T: SubAssign> SubAssign<Vec2<T>> for Vec2<T> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+// vector * scalar
+impl<T: Copy + Mul<Output=T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self::Output {
+        Vec2 {
+            x: self.x * other,
+            y: self.y * other,
+        }
+    }
+}
+
+// vector *= scalar
+impl<T: Copy + MulAssign> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x *= other;
+        self.y *= other;
+    }
+}
+
+// vector / scalar
+impl<T: Copy + Div<Output=T>> Div<T> for Vec2<T> {
+    type Output = Self;
+    fn div(self,other: T) -> Self::Output {
+        Vec2 {
+            x: self.x / other,
+            y: self.y / other,
+        }
+    }
+}
+
+// vector /= scalar
+impl<T: Copy + DivAssign> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x /= other;
+        self.y /= other;
+    }
+}
+
+// -vector
+impl<T: Neg<Output=T>> Neg for Vec2<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Vec2 {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32v2 = Vec2<f32>;
+#[allow(non_camel_case_types)]
+pub type f64v2 = Vec2<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors() {
+        let p = Vec2 { x: 3i32,y: -2, };
+        let n4: Vec<_> = p.neighbors4().collect();
+        assert_eq!(n4,[Vec2 { x: 2,y: -2, },Vec2 { x: 4,y: -2, },Vec2 { x: 3,y: -3, },Vec2 { x: 3,y: -1, }]);
+        let n8: Vec<_> = p.neighbors8().collect();
+        assert_eq!(n8.len(),8);
+        assert!(n8.iter().all(|n| n.chebyshev_distance(p) == 1));
+
+        // neighbors outside the range of the type are skipped
+        let edge = Vec2 { x: 0u32,y: 5, };
+        let n4: Vec<_> = edge.neighbors4().collect();
+        assert_eq!(n4,[Vec2 { x: 1,y: 5, },Vec2 { x: 0,y: 4, },Vec2 { x: 0,y: 6, }]);
+        assert_eq!(edge.neighbors8().count(),5);
+        assert_eq!(Vec2 { x: 0u8,y: 0, }.neighbors8().count(),3);
+        assert_eq!(Vec2 { x: i32::MAX,y: i32::MIN, }.neighbors4().count(),2);
+        assert_eq!(Vec2 { x: i32::MAX,y: i32::MIN, }.neighbors8().count(),3);
+    }
+
+    #[test]
+    fn euclidean_division() {
+        let a = Vec2 { x: -7i32,y: 7, };
+        let b = Vec2 { x: 3,y: -3, };
+        assert_eq!(a.div_euclid(b),Vec2 { x: -3,y: -2, });
+        assert_eq!(a.rem_euclid(b),Vec2 { x: 2,y: 1, });
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2 { x: 1i32,y: -4, };
+        let b = Vec2 { x: -2,y: 1, };
+        assert_eq!(a.manhattan_distance(b),8);
+        assert_eq!(a.chebyshev_distance(b),5);
+        let a = Vec2 { x: 2u32,y: 9, };
+        let b = Vec2 { x: 7,y: 3, };
+        assert_eq!(a.manhattan_distance(b),11);
+        assert_eq!(b.manhattan_distance(a),11);
+        assert_eq!(a.chebyshev_distance(b),6);
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Vec2 { x: -1.5f32,y: 2.5, };
+        assert_eq!(Vec2::<i32>::from_f32(v,Rounding::Floor),Vec2 { x: -2,y: 2, });
+        assert_eq!(Vec2::<i32>::from_f32(v,Rounding::Ceil),Vec2 { x: -1,y: 3, });
+        assert_eq!(Vec2::<i32>::from_f32(v,Rounding::Round),Vec2 { x: -2,y: 3, });
+        assert_eq!(Vec2::<i32>::from_f32(v,Rounding::Trunc),Vec2 { x: -1,y: 2, });
+        let v = Vec2 { x: -0.25f64,y: 3.75, };
+        assert_eq!(Vec2::<i64>::from_f64(v,Rounding::Floor),Vec2 { x: -1,y: 3, });
+        assert_eq!(Vec2::<i64>::from_f64(v,Rounding::Ceil),Vec2 { x: 0,y: 4, });
+        assert_eq!(Vec2::<i64>::from_f64(v,Rounding::Round),Vec2 { x: 0,y: 4, });
+        assert_eq!(Vec2::<i64>::from_f64(v,Rounding::Trunc),Vec2 { x: 0,y: 3, });
+    }
+
+    #[test]
+    fn slices() {
+        let mut v = Vec2 { x: 1.5f64,y: -2.0, };
+        assert_eq!(v.as_ref(),[1.5,-2.0]);
+        v.as_mut()[1] = 4.0;
+        assert_eq!(v.y,4.0);
+        let mut v = Vec2 { x: 7u8,y: 9, };
+        v.as_mut().swap(0,1);
+        assert_eq!(v.as_ref(),[9,7]);
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vec2 { x: 1,y: 2, };
+        assert_eq!(<[i32; 2]>::from(v),[1,2]);
+        assert_eq!(<(i32,i32)>::from(v),(1,2));
+        assert_eq!(Vec2::from([1,2]),v);
+        assert_eq!(Vec2::from(&[1,2]),v);
+        assert_eq!(Vec2::from((1,2)),v);
+        assert_eq!(v.extend(3),Vec3 { x: 1,y: 2,z: 3, });
+        assert_eq!(v.yx(),Vec2 { x: 2,y: 1, });
+    }
+
+    #[test]
+    fn normalize() {
+        let v = Vec2 { x: 3.0f64,y: -4.0, };
+        assert_eq!(v.length(),5.0);
+        assert_eq!(v.try_normalize(),Some(Vec2 { x: 0.6,y: -0.8, }));
+        assert_eq!(Vec2 { x: 0.0f64,y: 0.0, }.try_normalize(),None);
+    }
+}
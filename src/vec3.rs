@@ -1,6 +1,718 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+    },
+};
+
+/// 3D vector template.
+///
+/// A 3D vector describes a position, direction or displacement in space, with components `x`, `y` and `z`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as integers, [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
+#[repr(C)]
 pub struct Vec3<T> {
     pub x: T,
     pub y: T,
     pub z: T,
-}
\ No newline at end of file
+}
+
+/// Display the vector as `(x,y,z)`.
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{},{})",self.x,self.y,self.z)
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+
+    /// return copy with `x` replaced.
+    pub fn with_x(self,x: T) -> Self {
+        Vec3 { x,y: self.y,z: self.z, }
+    }
+
+    /// return copy with `y` replaced.
+    pub fn with_y(self,y: T) -> Self {
+        Vec3 { x: self.x,y,z: self.z, }
+    }
+
+    /// return copy with `z` replaced.
+    pub fn with_z(self,z: T) -> Self {
+        Vec3 { x: self.x,y: self.y,z, }
+    }
+
+    /// extend to 4D vector.
+    pub fn extend(self,w: T) -> Vec4<T> {
+        Vec4 { x: self.x,y: self.y,z: self.z,w, }
+    }
+
+    /// truncate to 2D vector, dropping `z`.
+    pub fn truncate(self) -> Vec2<T> {
+        Vec2 { x: self.x,y: self.y, }
+    }
+
+    /// apply `f` to each component.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Vec3<U> {
+        Vec3 { x: f(self.x),y: f(self.y),z: f(self.z), }
+    }
+
+    /// apply `f` to each pair of components of `self` and `other`.
+    pub fn zip_map<U,V,F: FnMut(T,U) -> V>(self,other: Vec3<U>,mut f: F) -> Vec3<V> {
+        Vec3 { x: f(self.x,other.x),y: f(self.y,other.y),z: f(self.z,other.z), }
+    }
+
+    // swizzles
+    swizzle_vec2! {
+        xx: x x, xy: x y, xz: x z, yx: y x, yy: y y, yz: y z, zx: z x, zy: z y, zz: z z
+    }
+
+    swizzle_vec3! {
+        xxx: x x x, xxy: x x y, xxz: x x z, xyx: x y x, xyy: x y y, xyz: x y z, xzx: x z x, xzy: x z y, xzz: x z z,
+        yxx: y x x, yxy: y x y, yxz: y x z, yyx: y y x, yyy: y y y, yyz: y y z, yzx: y z x, yzy: y z y, yzz: y z z,
+        zxx: z x x, zxy: z x y, zxz: z x z, zyx: z y x, zyy: z y y, zyz: z y z, zzx: z z x, zzy: z z y, zzz: z z z
+    }
+
+    swizzle_vec4! {
+        xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxyx: x x y x, xxyy: x x y y, xxyz: x x y z, xxzx: x x z x,
+        xxzy: x x z y, xxzz: x x z z, xyxx: x y x x, xyxy: x y x y, xyxz: x y x z, xyyx: x y y x, xyyy: x y y y,
+        xyyz: x y y z, xyzx: x y z x, xyzy: x y z y, xyzz: x y z z, xzxx: x z x x, xzxy: x z x y, xzxz: x z x z,
+        xzyx: x z y x, xzyy: x z y y, xzyz: x z y z, xzzx: x z z x, xzzy: x z z y, xzzz: x z z z, yxxx: y x x x,
+        yxxy: y x x y, yxxz: y x x z, yxyx: y x y x, yxyy: y x y y, yxyz: y x y z, yxzx: y x z x, yxzy: y x z y,
+        yxzz: y x z z, yyxx: y y x x, yyxy: y y x y, yyxz: y y x z, yyyx: y y y x, yyyy: y y y y, yyyz: y y y z,
+        yyzx: y y z x, yyzy: y y z y, yyzz: y y z z, yzxx: y z x x, yzxy: y z x y, yzxz: y z x z, yzyx: y z y x,
+        yzyy: y z y y, yzyz: y z y z, yzzx: y z z x, yzzy: y z z y, yzzz: y z z z, zxxx: z x x x, zxxy: z x x y,
+        zxxz: z x x z, zxyx: z x y x, zxyy: z x y y, zxyz: z x y z, zxzx: z x z x, zxzy: z x z y, zxzz: z x z z,
+        zyxx: z y x x, zyxy: z y x y, zyxz: z y x z, zyyx: z y y x, zyyy: z y y y, zyyz: z y y z, zyzx: z y z x,
+        zyzy: z y z y, zyzz: z y z z, zzxx: z z x x, zzxy: z z x y, zzxz: z z x z, zzyx: z z y x, zzyy: z z y y,
+        zzyz: z z y z, zzzx: z z z x, zzzy: z z z y, zzzz: z z z z
+    }
+}
+
+impl<T: Copy + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vec3<T> {
+
+    /// cross product.
+    pub fn cross(self,other: Self) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    /// scalar triple product `self · (b × c)`, the signed volume of the parallelepiped spanned by the three vectors.
+    pub fn scalar_triple(self,b: Self,c: Self) -> T {
+        self.dot(b.cross(c))
+    }
+
+    /// vector triple product `self × (b × c)`.
+    pub fn vector_triple(self,b: Self,c: Self) -> Self {
+        self.cross(b.cross(c))
+    }
+
+    /// projection of this vector onto `other`.
+    pub fn project_onto(self,other: Self) -> Self {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// part of this vector that is perpendicular to `other`.
+    pub fn reject_from(self,other: Self) -> Self {
+        self - self.project_onto(other)
+    }
+}
+
+impl<T: Copy + One + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vec3<T> {
+
+    /// reflect this (incident) vector around unit-length `normal`.
+    pub fn reflect(self,normal: Self) -> Self {
+        let d = normal.dot(self);
+        self - normal * ((T::ONE + T::ONE) * d)
+    }
+}
+
+impl<T: Copy + Zero + One + PartialOrd + Real + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vec3<T> {
+
+    /// refract this unit-length (incident) vector through a surface with unit-length `normal`, where `eta` is the ratio of
+    /// the refraction indices. Returns `None` on total internal reflection.
+    pub fn refract(self,normal: Self,eta: T) -> Option<Self> {
+        let d = normal.dot(self);
+        let k = T::ONE - eta * eta * (T::ONE - d * d);
+        if k < T::ZERO {
+            None
+        }
+        else {
+            Some(self * eta - normal * (eta * d + k.sqrt()))
+        }
+    }
+
+    /// angle between this vector and `other`, in radians.
+    pub fn angle_between(self,other: Self) -> T {
+        self.cross(other).length().atan2(self.dot(other))
+    }
+
+    /// a unit vector perpendicular to this one, for completing a basis when no other direction is at hand.
+    pub fn any_perpendicular(self) -> Self {
+        let axis = if (self.x.abs() <= self.y.abs()) && (self.x.abs() <= self.z.abs()) {
+            Vec3 { x: T::ONE,y: T::ZERO,z: T::ZERO, }
+        }
+        else if self.y.abs() <= self.z.abs() {
+            Vec3 { x: T::ZERO,y: T::ONE,z: T::ZERO, }
+        }
+        else {
+            Vec3 { x: T::ZERO,y: T::ZERO,z: T::ONE, }
+        };
+        self.cross(axis).normalize()
+    }
+}
+
+impl<T: Copy + Zero + One> Vec3<T> {
+
+    /// homogeneous coordinates of this point (`w` = 1), affected by translation.
+    pub fn to_homogeneous_point(self) -> Vec4<T> {
+        Vec4 { x: self.x,y: self.y,z: self.z,w: T::ONE, }
+    }
+
+    /// homogeneous coordinates of this direction (`w` = 0), not affected by translation.
+    pub fn to_homogeneous_vector(self) -> Vec4<T> {
+        Vec4 { x: self.x,y: self.y,z: self.z,w: T::ZERO, }
+    }
+}
+
+impl<T: Copy + Div<Output=T>> Vec3<T> {
+
+    /// 2D point from these homogeneous coordinates, dividing by `z` (perspective divide).
+    pub fn from_homogeneous(self) -> Vec2<T> {
+        Vec2 { x: self.x / self.z,y: self.y / self.z, }
+    }
+}
+
+impl<T: Copy + Real> Vec3<T> {
+
+    /// component-wise minimum.
+    pub fn min(self,other: Self) -> Self {
+        Vec3 { x: self.x.min(other.x),y: self.y.min(other.y),z: self.z.min(other.z), }
+    }
+
+    /// component-wise maximum.
+    pub fn max(self,other: Self) -> Self {
+        Vec3 { x: self.x.max(other.x),y: self.y.max(other.y),z: self.z.max(other.z), }
+    }
+
+    /// component-wise clamp between `min` and `max`.
+    pub fn clamp(self,min: Self,max: Self) -> Self {
+        Vec3 { x: self.x.clamp(min.x,max.x),y: self.y.clamp(min.y,max.y),z: self.z.clamp(min.z,max.z), }
+    }
+
+    /// component-wise absolute value.
+    pub fn abs(self) -> Self {
+        self.map(|c| c.abs())
+    }
+
+    /// component-wise floor.
+    pub fn floor(self) -> Self {
+        self.map(|c| c.floor())
+    }
+
+    /// component-wise ceiling.
+    pub fn ceil(self) -> Self {
+        self.map(|c| c.ceil())
+    }
+
+    /// component-wise rounding.
+    pub fn round(self) -> Self {
+        self.map(|c| c.round())
+    }
+
+    /// component-wise sign.
+    pub fn signum(self) -> Self {
+        self.map(|c| c.signum())
+    }
+}
+
+impl<T: Copy + Add<Output=T>> Vec3<T> {
+
+    /// sum of the components.
+    pub fn sum(self) -> T {
+        self.x + self.y + self.z
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Vec3<T> {
+
+    /// product of the components.
+    pub fn product(self) -> T {
+        self.x * self.y * self.z
+    }
+}
+
+impl<T: Copy + PartialOrd> Vec3<T> {
+
+    /// smallest component.
+    pub fn min_element(self) -> T {
+        let mut result = self.x;
+        if self.y < result {
+            result = self.y;
+        }
+        if self.z < result {
+            result = self.z;
+        }
+        result
+    }
+
+    /// largest component.
+    pub fn max_element(self) -> T {
+        let mut result = self.x;
+        if self.y > result {
+            result = self.y;
+        }
+        if self.z > result {
+            result = self.z;
+        }
+        result
+    }
+
+    /// index of the largest component (the first one if there are several).
+    pub fn argmax(self) -> usize {
+        let mut index = 0;
+        let mut value = self.x;
+        if self.y > value {
+            index = 1;
+            value = self.y;
+        }
+        if self.z > value {
+            index = 2;
+        }
+        index
+    }
+}
+
+impl<T: Copy + Unsigned> Vec3<T> {
+
+    /// component-wise Euclidean division.
+    pub fn div_euclid(self,rhs: Self) -> Self {
+        Vec3 { x: self.x.div_euclid(rhs.x),y: self.y.div_euclid(rhs.y),z: self.z.div_euclid(rhs.z), }
+    }
+
+    /// component-wise Euclidean remainder, always non-negative.
+    pub fn rem_euclid(self,rhs: Self) -> Self {
+        Vec3 { x: self.x.rem_euclid(rhs.x),y: self.y.rem_euclid(rhs.y),z: self.z.rem_euclid(rhs.z), }
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output=T> + Sub<Output=T>> Vec3<T> {
+
+    // component-wise absolute difference, also works for unsigned integers
+    fn abs_diff(self,other: Self) -> Self {
+        Vec3 { x: if self.x > other.x { self.x - other.x } else { other.x - self.x },y: if self.y > other.y { self.y - other.y } else { other.y - self.y },z: if self.z > other.z { self.z - other.z } else { other.z - self.z }, }
+    }
+
+    /// Manhattan (taxicab) distance.
+    pub fn manhattan_distance(self,other: Self) -> T {
+        let d = self.abs_diff(other);
+        d.x + d.y + d.z
+    }
+
+    /// Chebyshev (chessboard) distance.
+    pub fn chebyshev_distance(self,other: Self) -> T {
+        let d = self.abs_diff(other);
+        let mut result = d.x;
+        if d.y > result {
+            result = d.y;
+        }
+        if d.z > result {
+            result = d.z;
+        }
+        result
+    }
+}
+
+impl<T: Copy + One + PartialEq + Unsigned + Add<Output=T> + Sub<Output=T>> Vec3<T> {
+
+    /// the 6 face-connected neighbors on an integer grid, skipping the ones outside the range of `T`.
+    pub fn neighbors6(self) -> impl Iterator<Item=Self> {
+        let [x0,x1,x2] = grid_steps(self.x);
+        let [y0,y1,y2] = grid_steps(self.y);
+        let [z0,z1,z2] = grid_steps(self.z);
+        IntoIterator::into_iter([
+            (x0,y1,z1),
+            (x2,y1,z1),
+            (x1,y0,z1),
+            (x1,y2,z1),
+            (x1,y1,z0),
+            (x1,y1,z2),
+        ]).filter_map(|(x,y,z)| Some(Vec3 { x: x?,y: y?,z: z?, }))
+    }
+
+    /// the 26 face-, edge- and corner-connected neighbors on an integer grid, skipping the ones outside the range of
+    /// `T`.
+    pub fn neighbors26(self) -> impl Iterator<Item=Self> {
+        let xs = grid_steps(self.x);
+        let ys = grid_steps(self.y);
+        let zs = grid_steps(self.z);
+        IntoIterator::into_iter(zs).flatten().flat_map(move |z| {
+            IntoIterator::into_iter(ys).flatten().flat_map(move |y| {
+                IntoIterator::into_iter(xs).flatten().map(move |x| Vec3 { x,y,z, })
+            })
+        }).filter(move |v| *v != self)
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from(array: [T; 3]) -> Self {
+        let [x,y,z] = array;
+        Vec3 { x,y,z, }
+    }
+}
+
+impl<T: Copy> From<&[T; 3]> for Vec3<T> {
+    fn from(slice: &[T; 3]) -> Self {
+        Vec3 { x: slice[0],y: slice[1],z: slice[2], }
+    }
+}
+
+impl<T> From<Vec3<T>> for [T; 3] {
+    fn from(vector: Vec3<T>) -> Self {
+        [vector.x,vector.y,vector.z]
+    }
+}
+
+impl<T> From<(T,T,T)> for Vec3<T> {
+    fn from(tuple: (T,T,T)) -> Self {
+        Vec3 { x: tuple.0,y: tuple.1,z: tuple.2, }
+    }
+}
+
+impl<T> From<Vec3<T>> for (T,T,T) {
+    fn from(vector: Vec3<T>) -> Self {
+        (vector.x,vector.y,vector.z)
+    }
+}
+
+// the components are laid out contiguously because of #[repr(C)]
+impl<T> AsRef<[T]> for Vec3<T> {
+    fn as_ref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const T,3) }
+    }
+}
+
+impl<T> AsMut<[T]> for Vec3<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T,3) }
+    }
+}
+
+// vector == vector
+impl<T: PartialEq> PartialEq<Vec3<T>> for Vec3<T> {
+    fn eq(&self,other: &Vec3<T>) -> bool {
+        (self.x == other.x) &&
+        (self.y == other.y) &&
+        (self.z == other.z)
+    }
+}
+
+// scalar * vector
+macro_rules! scalar_vec3 {
+    ($($t:ty)*) => ($(
+        impl Mul<Vec3<$t>> for $t {
+            type Output = Vec3<$t>;
+            fn mul(self,other: Vec3<$t>) -> Vec3<$t> {
+                Vec3 {
+                    x: self * other.x,
+                    y: self * other.y,
+                    z: self * other.z,
+                }
+            }
+        }
+    )*)
+}
+
+scalar_vec3! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+// integer vector <-> float vector
+macro_rules! int_vec3 {
+    ($($t:ty)*) => ($(
+        impl Vec3<$t> {
+
+            /// convert from `f32` vector, using `rounding`.
+            pub fn from_f32(v: Vec3<f32>,rounding: Rounding) -> Self {
+                Vec3 { x: rounding.apply(v.x) as $t,y: rounding.apply(v.y) as $t,z: rounding.apply(v.z) as $t, }
+            }
+
+            /// convert from `f64` vector, using `rounding`.
+            pub fn from_f64(v: Vec3<f64>,rounding: Rounding) -> Self {
+                Vec3 { x: rounding.apply(v.x) as $t,y: rounding.apply(v.y) as $t,z: rounding.apply(v.z) as $t, }
+            }
+
+            /// convert to `f32` vector.
+            pub fn to_f32(self) -> Vec3<f32> {
+                Vec3 { x: self.x as f32,y: self.y as f32,z: self.z as f32, }
+            }
+
+            /// convert to `f64` vector.
+            pub fn to_f64(self) -> Vec3<f64> {
+                Vec3 { x: self.x as f64,y: self.y as f64,z: self.z as f64, }
+            }
+        }
+    )*)
+}
+
+int_vec3! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 }
+
+// rational * vector
+macro_rules! rational_vec3 {
+    ($(($t:ty,$ut:ty))*) => ($(
+        impl Mul<Vec3<Rational<$t,$ut>>> for Rational<$t,$ut> {
+            type Output = Vec3<Rational<$t,$ut>>;
+            fn mul(self,other: Vec3<Rational<$t,$ut>>) -> Vec3<Rational<$t,$ut>> {
+                Vec3 {
+                    x: self * other.x,
+                    y: self * other.y,
+                    z: self * other.z,
+                }
+            }
+        }
+    )*)
+}
+
+rational_vec3! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+
+// fixed * vector
+impl<T,const B: usize> Mul<Vec3<Fixed<T,B>>> for Fixed<T,B> where Fixed<T,B>: Copy + Mul<Output=Fixed<T,B>> {
+    type Output = Vec3<Fixed<T,B>>;
+    fn mul(self,other: Vec3<Fixed<T,B>>) -> Vec3<Fixed<T,B>> {
+        Vec3 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+        }
+    }
+}
+
+// vector + vector
+impl<T: Add<Output=T>> Add<Vec3<T>> for Vec3<T> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self::Output {
+        Vec3 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+// vector += vector
+impl<T: AddAssign> AddAssign<Vec3<T>> for Vec3<T> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+// vector - vector
+impl<T: Sub<Output=T>> Sub<Vec3<T>> for Vec3<T> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self::Output {
+        Vec3 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+// vector -= vector
+impl<T: SubAssign> SubAssign<Vec3<T>> for Vec3<T> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+// vector * scalar
+impl<T: Copy + Mul<Output=T>> Mul<T> for Vec3<T> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self::Output {
+        Vec3 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+// vector *= scalar
+impl<T: Copy + MulAssign> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+    }
+}
+
+// vector / scalar
+impl<T: Copy + Div<Output=T>> Div<T> for Vec3<T> {
+    type Output = Self;
+    fn div(self,other: T) -> Self::Output {
+        Vec3 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+// vector /= scalar
+impl<T: Copy + DivAssign> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+    }
+}
+
+// -vector
+impl<T: Neg<Output=T>> Neg for Vec3<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Vec3 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32v3 = Vec3<f32>;
+#[allow(non_camel_case_types)]
+pub type f64v3 = Vec3<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors() {
+        let p = Vec3 { x: 3i32,y: -2,z: 0, };
+        let n6: Vec<_> = p.neighbors6().collect();
+        assert_eq!(n6.len(),6);
+        assert!(n6.iter().all(|n| n.manhattan_distance(p) == 1));
+        let n26: Vec<_> = p.neighbors26().collect();
+        assert_eq!(n26.len(),26);
+        assert!(n26.iter().all(|n| n.chebyshev_distance(p) == 1));
+
+        // neighbors outside the range of the type are skipped
+        let corner = Vec3 { x: 0u32,y: 0,z: 5, };
+        let n6: Vec<_> = corner.neighbors6().collect();
+        assert_eq!(n6,[Vec3 { x: 1,y: 0,z: 5, },Vec3 { x: 0,y: 1,z: 5, },Vec3 { x: 0,y: 0,z: 4, },Vec3 { x: 0,y: 0,z: 6, }]);
+        assert_eq!(corner.neighbors26().count(),11);
+        assert_eq!(Vec3 { x: i8::MAX,y: i8::MIN,z: i8::MAX, }.neighbors26().count(),7);
+    }
+
+    #[test]
+    fn euclidean_division() {
+        let a = Vec3 { x: -7i32,y: 7,z: -6, };
+        let b = Vec3 { x: 3,y: -3,z: 3, };
+        assert_eq!(a.div_euclid(b),Vec3 { x: -3,y: -2,z: -2, });
+        assert_eq!(a.rem_euclid(b),Vec3 { x: 2,y: 1,z: 0, });
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec3 { x: 1i32,y: -4,z: 2, };
+        let b = Vec3 { x: -2,y: 1,z: 2, };
+        assert_eq!(a.manhattan_distance(b),8);
+        assert_eq!(a.chebyshev_distance(b),5);
+        let a = Vec3 { x: 2u32,y: 9,z: 0, };
+        let b = Vec3 { x: 7,y: 3,z: 8, };
+        assert_eq!(a.manhattan_distance(b),19);
+        assert_eq!(b.chebyshev_distance(a),8);
+    }
+
+    #[test]
+    fn rounding() {
+        let v = Vec3 { x: -1.5f32,y: 2.5,z: 0.4, };
+        assert_eq!(Vec3::<i32>::from_f32(v,Rounding::Floor),Vec3 { x: -2,y: 2,z: 0, });
+        assert_eq!(Vec3::<i32>::from_f32(v,Rounding::Ceil),Vec3 { x: -1,y: 3,z: 1, });
+        assert_eq!(Vec3::<i32>::from_f32(v,Rounding::Round),Vec3 { x: -2,y: 3,z: 0, });
+        assert_eq!(Vec3::<i32>::from_f32(v,Rounding::Trunc),Vec3 { x: -1,y: 2,z: 0, });
+        let v = Vec3 { x: -0.25f64,y: 3.75,z: -2.5, };
+        assert_eq!(Vec3::<i64>::from_f64(v,Rounding::Floor),Vec3 { x: -1,y: 3,z: -3, });
+        assert_eq!(Vec3::<i64>::from_f64(v,Rounding::Ceil),Vec3 { x: 0,y: 4,z: -2, });
+        assert_eq!(Vec3::<i64>::from_f64(v,Rounding::Round),Vec3 { x: 0,y: 4,z: -3, });
+        assert_eq!(Vec3::<i64>::from_f64(v,Rounding::Trunc),Vec3 { x: 0,y: 3,z: -2, });
+    }
+
+    #[test]
+    fn any_perpendicular() {
+        for v in [
+            Vec3 { x: 1.0,y: 0.0,z: 0.0, },
+            Vec3 { x: 0.0,y: -2.0,z: 0.0, },
+            Vec3 { x: 0.0,y: 0.0,z: 0.5, },
+            Vec3 { x: 1.0,y: 2.0,z: -3.0, },
+            Vec3 { x: 1e-3,y: 1.0,z: 1.0, },
+        ] {
+            let p = v.any_perpendicular();
+            assert!(p.dot(v).abs() < 1e-12);
+            assert!((p.length() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn slices() {
+        let mut v = Vec3 { x: 1.5f64,y: -2.0,z: 0.25, };
+        assert_eq!(v.as_ref(),[1.5,-2.0,0.25]);
+        v.as_mut()[2] = 4.0;
+        assert_eq!(v.z,4.0);
+        let mut v = Vec3 { x: 7u8,y: 8,z: 9, };
+        v.as_mut().reverse();
+        assert_eq!(v,Vec3 { x: 9,y: 8,z: 7, });
+    }
+
+    #[test]
+    fn homogeneous() {
+        let p = Vec3 { x: 1.0,y: -2.0,z: 3.0, };
+        assert_eq!(p.to_homogeneous_point(),Vec4 { x: 1.0,y: -2.0,z: 3.0,w: 1.0, });
+        assert_eq!(p.to_homogeneous_vector(),Vec4 { x: 1.0,y: -2.0,z: 3.0,w: 0.0, });
+        assert_eq!(Vec3 { x: 2.0,y: -4.0,z: 2.0, }.from_homogeneous(),Vec2 { x: 1.0,y: -2.0, });
+        assert_eq!(Vec2 { x: 1.0,y: -2.0, }.to_homogeneous_point().from_homogeneous(),Vec2 { x: 1.0,y: -2.0, });
+    }
+
+    #[test]
+    fn reflect_and_refract() {
+        let normal = Vec3 { x: 0.0,y: 1.0,z: 0.0, };
+        let incident = Vec3 { x: 1.0,y: -1.0,z: 0.0, }.normalize();
+        assert_eq!(incident.reflect(normal),Vec3 { x: incident.x,y: -incident.y,z: 0.0, });
+
+        // straight through at normal incidence
+        let down = Vec3 { x: 0.0,y: -1.0,z: 0.0, };
+        assert_eq!(down.refract(normal,1.5),Some(down));
+
+        // into a denser medium, Snell's law holds for the tangential part
+        let r = incident.refract(normal,1.0 / 1.5).unwrap();
+        assert!((r.x - incident.x / 1.5).abs() < 1e-12);
+        assert!((r.length() - 1.0).abs() < 1e-12);
+        assert!(r.y < 0.0);
+
+        // total internal reflection beyond the critical angle
+        assert_eq!(incident.refract(normal,1.5),None);
+        let grazing = Vec3 { x: 1.0,y: -0.2,z: 0.0, }.normalize();
+        assert_eq!(grazing.refract(normal,1.5),None);
+    }
+
+    #[test]
+    fn normalize() {
+        let v = Vec3 { x: 2.0f64,y: -3.0,z: 6.0, };
+        assert_eq!(v.length(),7.0);
+        assert!((v.try_normalize().unwrap() - v / 7.0).length() < 1e-15);
+        assert_eq!(Vec3 { x: 0.0f64,y: 0.0,z: 0.0, }.try_normalize(),None);
+        assert_eq!(Vec3 { x: 1.0,y: 0.0,z: 0.0, }.cross(Vec3 { x: 0.0,y: 1.0,z: 0.0, }),Vec3 { x: 0.0,y: 0.0,z: 1.0, });
+    }
+}
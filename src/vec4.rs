@@ -1,7 +1,497 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+    },
+};
+
+/// 4D vector template.
+///
+/// A 4D vector describes a homogeneous position or direction, a color, or any other quantity with components `x`, `y`,
+/// `z` and `w`.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as integers, [`Rational`] and [`Fixed`] types.
 #[derive(Copy,Clone,Debug)]
+#[repr(C)]
 pub struct Vec4<T> {
     pub x: T,
     pub y: T,
     pub z: T,
     pub w: T,
-}
\ No newline at end of file
+}
+
+/// Display the vector as `(x,y,z,w)`.
+impl<T: Display> Display for Vec4<T> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"({},{},{},{})",self.x,self.y,self.z,self.w)
+    }
+}
+
+impl<T: Copy> Vec4<T> {
+
+    /// return copy with `x` replaced.
+    pub fn with_x(self,x: T) -> Self {
+        Vec4 { x,y: self.y,z: self.z,w: self.w, }
+    }
+
+    /// return copy with `y` replaced.
+    pub fn with_y(self,y: T) -> Self {
+        Vec4 { x: self.x,y,z: self.z,w: self.w, }
+    }
+
+    /// return copy with `z` replaced.
+    pub fn with_z(self,z: T) -> Self {
+        Vec4 { x: self.x,y: self.y,z,w: self.w, }
+    }
+
+    /// return copy with `w` replaced.
+    pub fn with_w(self,w: T) -> Self {
+        Vec4 { x: self.x,y: self.y,z: self.z,w, }
+    }
+
+    /// truncate to 3D vector, dropping `w`.
+    pub fn truncate(self) -> Vec3<T> {
+        Vec3 { x: self.x,y: self.y,z: self.z, }
+    }
+
+    /// apply `f` to each component.
+    pub fn map<U,F: FnMut(T) -> U>(self,mut f: F) -> Vec4<U> {
+        Vec4 { x: f(self.x),y: f(self.y),z: f(self.z),w: f(self.w), }
+    }
+
+    /// apply `f` to each pair of components of `self` and `other`.
+    pub fn zip_map<U,V,F: FnMut(T,U) -> V>(self,other: Vec4<U>,mut f: F) -> Vec4<V> {
+        Vec4 { x: f(self.x,other.x),y: f(self.y,other.y),z: f(self.z,other.z),w: f(self.w,other.w), }
+    }
+
+    // swizzles
+    swizzle_vec2! {
+        xx: x x, xy: x y, xz: x z, xw: x w, yx: y x, yy: y y, yz: y z, yw: y w, zx: z x, zy: z y, zz: z z, zw: z w,
+        wx: w x, wy: w y, wz: w z, ww: w w
+    }
+
+    swizzle_vec3! {
+        xxx: x x x, xxy: x x y, xxz: x x z, xxw: x x w, xyx: x y x, xyy: x y y, xyz: x y z, xyw: x y w, xzx: x z x,
+        xzy: x z y, xzz: x z z, xzw: x z w, xwx: x w x, xwy: x w y, xwz: x w z, xww: x w w, yxx: y x x, yxy: y x y,
+        yxz: y x z, yxw: y x w, yyx: y y x, yyy: y y y, yyz: y y z, yyw: y y w, yzx: y z x, yzy: y z y, yzz: y z z,
+        yzw: y z w, ywx: y w x, ywy: y w y, ywz: y w z, yww: y w w, zxx: z x x, zxy: z x y, zxz: z x z, zxw: z x w,
+        zyx: z y x, zyy: z y y, zyz: z y z, zyw: z y w, zzx: z z x, zzy: z z y, zzz: z z z, zzw: z z w, zwx: z w x,
+        zwy: z w y, zwz: z w z, zww: z w w, wxx: w x x, wxy: w x y, wxz: w x z, wxw: w x w, wyx: w y x, wyy: w y y,
+        wyz: w y z, wyw: w y w, wzx: w z x, wzy: w z y, wzz: w z z, wzw: w z w, wwx: w w x, wwy: w w y, wwz: w w z,
+        www: w w w
+    }
+
+    swizzle_vec4! {
+        xxxx: x x x x, xxxy: x x x y, xxxz: x x x z, xxxw: x x x w, xxyx: x x y x, xxyy: x x y y, xxyz: x x y z,
+        xxyw: x x y w, xxzx: x x z x, xxzy: x x z y, xxzz: x x z z, xxzw: x x z w, xxwx: x x w x, xxwy: x x w y,
+        xxwz: x x w z, xxww: x x w w, xyxx: x y x x, xyxy: x y x y, xyxz: x y x z, xyxw: x y x w, xyyx: x y y x,
+        xyyy: x y y y, xyyz: x y y z, xyyw: x y y w, xyzx: x y z x, xyzy: x y z y, xyzz: x y z z, xyzw: x y z w,
+        xywx: x y w x, xywy: x y w y, xywz: x y w z, xyww: x y w w, xzxx: x z x x, xzxy: x z x y, xzxz: x z x z,
+        xzxw: x z x w, xzyx: x z y x, xzyy: x z y y, xzyz: x z y z, xzyw: x z y w, xzzx: x z z x, xzzy: x z z y,
+        xzzz: x z z z, xzzw: x z z w, xzwx: x z w x, xzwy: x z w y, xzwz: x z w z, xzww: x z w w, xwxx: x w x x,
+        xwxy: x w x y, xwxz: x w x z, xwxw: x w x w, xwyx: x w y x, xwyy: x w y y, xwyz: x w y z, xwyw: x w y w,
+        xwzx: x w z x, xwzy: x w z y, xwzz: x w z z, xwzw: x w z w, xwwx: x w w x, xwwy: x w w y, xwwz: x w w z,
+        xwww: x w w w, yxxx: y x x x, yxxy: y x x y, yxxz: y x x z, yxxw: y x x w, yxyx: y x y x, yxyy: y x y y,
+        yxyz: y x y z, yxyw: y x y w, yxzx: y x z x, yxzy: y x z y, yxzz: y x z z, yxzw: y x z w, yxwx: y x w x,
+        yxwy: y x w y, yxwz: y x w z, yxww: y x w w, yyxx: y y x x, yyxy: y y x y, yyxz: y y x z, yyxw: y y x w,
+        yyyx: y y y x, yyyy: y y y y, yyyz: y y y z, yyyw: y y y w, yyzx: y y z x, yyzy: y y z y, yyzz: y y z z,
+        yyzw: y y z w, yywx: y y w x, yywy: y y w y, yywz: y y w z, yyww: y y w w, yzxx: y z x x, yzxy: y z x y,
+        yzxz: y z x z, yzxw: y z x w, yzyx: y z y x, yzyy: y z y y, yzyz: y z y z, yzyw: y z y w, yzzx: y z z x,
+        yzzy: y z z y, yzzz: y z z z, yzzw: y z z w, yzwx: y z w x, yzwy: y z w y, yzwz: y z w z, yzww: y z w w,
+        ywxx: y w x x, ywxy: y w x y, ywxz: y w x z, ywxw: y w x w, ywyx: y w y x, ywyy: y w y y, ywyz: y w y z,
+        ywyw: y w y w, ywzx: y w z x, ywzy: y w z y, ywzz: y w z z, ywzw: y w z w, ywwx: y w w x, ywwy: y w w y,
+        ywwz: y w w z, ywww: y w w w, zxxx: z x x x, zxxy: z x x y, zxxz: z x x z, zxxw: z x x w, zxyx: z x y x,
+        zxyy: z x y y, zxyz: z x y z, zxyw: z x y w, zxzx: z x z x, zxzy: z x z y, zxzz: z x z z, zxzw: z x z w,
+        zxwx: z x w x, zxwy: z x w y, zxwz: z x w z, zxww: z x w w, zyxx: z y x x, zyxy: z y x y, zyxz: z y x z,
+        zyxw: z y x w, zyyx: z y y x, zyyy: z y y y, zyyz: z y y z, zyyw: z y y w, zyzx: z y z x, zyzy: z y z y,
+        zyzz: z y z z, zyzw: z y z w, zywx: z y w x, zywy: z y w y, zywz: z y w z, zyww: z y w w, zzxx: z z x x,
+        zzxy: z z x y, zzxz: z z x z, zzxw: z z x w, zzyx: z z y x, zzyy: z z y y, zzyz: z z y z, zzyw: z z y w,
+        zzzx: z z z x, zzzy: z z z y, zzzz: z z z z, zzzw: z z z w, zzwx: z z w x, zzwy: z z w y, zzwz: z z w z,
+        zzww: z z w w, zwxx: z w x x, zwxy: z w x y, zwxz: z w x z, zwxw: z w x w, zwyx: z w y x, zwyy: z w y y,
+        zwyz: z w y z, zwyw: z w y w, zwzx: z w z x, zwzy: z w z y, zwzz: z w z z, zwzw: z w z w, zwwx: z w w x,
+        zwwy: z w w y, zwwz: z w w z, zwww: z w w w, wxxx: w x x x, wxxy: w x x y, wxxz: w x x z, wxxw: w x x w,
+        wxyx: w x y x, wxyy: w x y y, wxyz: w x y z, wxyw: w x y w, wxzx: w x z x, wxzy: w x z y, wxzz: w x z z,
+        wxzw: w x z w, wxwx: w x w x, wxwy: w x w y, wxwz: w x w z, wxww: w x w w, wyxx: w y x x, wyxy: w y x y,
+        wyxz: w y x z, wyxw: w y x w, wyyx: w y y x, wyyy: w y y y, wyyz: w y y z, wyyw: w y y w, wyzx: w y z x,
+        wyzy: w y z y, wyzz: w y z z, wyzw: w y z w, wywx: w y w x, wywy: w y w y, wywz: w y w z, wyww: w y w w,
+        wzxx: w z x x, wzxy: w z x y, wzxz: w z x z, wzxw: w z x w, wzyx: w z y x, wzyy: w z y y, wzyz: w z y z,
+        wzyw: w z y w, wzzx: w z z x, wzzy: w z z y, wzzz: w z z z, wzzw: w z z w, wzwx: w z w x, wzwy: w z w y,
+        wzwz: w z w z, wzww: w z w w, wwxx: w w x x, wwxy: w w x y, wwxz: w w x z, wwxw: w w x w, wwyx: w w y x,
+        wwyy: w w y y, wwyz: w w y z, wwyw: w w y w, wwzx: w w z x, wwzy: w w z y, wwzz: w w z z, wwzw: w w z w,
+        wwwx: w w w x, wwwy: w w w y, wwwz: w w w z, wwww: w w w w
+    }
+}
+
+impl<T: Copy + Div<Output=T>> Vec4<T> {
+
+    /// 3D point from these homogeneous coordinates, dividing by `w` (perspective divide).
+    pub fn from_homogeneous(self) -> Vec3<T> {
+        Vec3 { x: self.x / self.w,y: self.y / self.w,z: self.z / self.w, }
+    }
+}
+
+impl<T: Copy + Real> Vec4<T> {
+
+    /// component-wise minimum.
+    pub fn min(self,other: Self) -> Self {
+        Vec4 { x: self.x.min(other.x),y: self.y.min(other.y),z: self.z.min(other.z),w: self.w.min(other.w), }
+    }
+
+    /// component-wise maximum.
+    pub fn max(self,other: Self) -> Self {
+        Vec4 { x: self.x.max(other.x),y: self.y.max(other.y),z: self.z.max(other.z),w: self.w.max(other.w), }
+    }
+
+    /// component-wise clamp between `min` and `max`.
+    pub fn clamp(self,min: Self,max: Self) -> Self {
+        Vec4 { x: self.x.clamp(min.x,max.x),y: self.y.clamp(min.y,max.y),z: self.z.clamp(min.z,max.z),w: self.w.clamp(min.w,max.w), }
+    }
+
+    /// component-wise absolute value.
+    pub fn abs(self) -> Self {
+        self.map(|c| c.abs())
+    }
+
+    /// component-wise floor.
+    pub fn floor(self) -> Self {
+        self.map(|c| c.floor())
+    }
+
+    /// component-wise ceiling.
+    pub fn ceil(self) -> Self {
+        self.map(|c| c.ceil())
+    }
+
+    /// component-wise rounding.
+    pub fn round(self) -> Self {
+        self.map(|c| c.round())
+    }
+
+    /// component-wise sign.
+    pub fn signum(self) -> Self {
+        self.map(|c| c.signum())
+    }
+}
+
+impl<T: Copy + Add<Output=T>> Vec4<T> {
+
+    /// sum of the components.
+    pub fn sum(self) -> T {
+        self.x + self.y + self.z + self.w
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Vec4<T> {
+
+    /// product of the components.
+    pub fn product(self) -> T {
+        self.x * self.y * self.z * self.w
+    }
+}
+
+impl<T: Copy + PartialOrd> Vec4<T> {
+
+    /// smallest component.
+    pub fn min_element(self) -> T {
+        let mut result = self.x;
+        if self.y < result {
+            result = self.y;
+        }
+        if self.z < result {
+            result = self.z;
+        }
+        if self.w < result {
+            result = self.w;
+        }
+        result
+    }
+
+    /// largest component.
+    pub fn max_element(self) -> T {
+        let mut result = self.x;
+        if self.y > result {
+            result = self.y;
+        }
+        if self.z > result {
+            result = self.z;
+        }
+        if self.w > result {
+            result = self.w;
+        }
+        result
+    }
+
+    /// index of the largest component (the first one if there are several).
+    pub fn argmax(self) -> usize {
+        let mut index = 0;
+        let mut value = self.x;
+        if self.y > value {
+            index = 1;
+            value = self.y;
+        }
+        if self.z > value {
+            index = 2;
+            value = self.z;
+        }
+        if self.w > value {
+            index = 3;
+        }
+        index
+    }
+}
+
+impl<T> From<[T; 4]> for Vec4<T> {
+    fn from(array: [T; 4]) -> Self {
+        let [x,y,z,w] = array;
+        Vec4 { x,y,z,w, }
+    }
+}
+
+impl<T: Copy> From<&[T; 4]> for Vec4<T> {
+    fn from(slice: &[T; 4]) -> Self {
+        Vec4 { x: slice[0],y: slice[1],z: slice[2],w: slice[3], }
+    }
+}
+
+impl<T> From<Vec4<T>> for [T; 4] {
+    fn from(vector: Vec4<T>) -> Self {
+        [vector.x,vector.y,vector.z,vector.w]
+    }
+}
+
+impl<T> From<(T,T,T,T)> for Vec4<T> {
+    fn from(tuple: (T,T,T,T)) -> Self {
+        Vec4 { x: tuple.0,y: tuple.1,z: tuple.2,w: tuple.3, }
+    }
+}
+
+impl<T> From<Vec4<T>> for (T,T,T,T) {
+    fn from(vector: Vec4<T>) -> Self {
+        (vector.x,vector.y,vector.z,vector.w)
+    }
+}
+
+// the components are laid out contiguously because of #[repr(C)]
+impl<T> AsRef<[T]> for Vec4<T> {
+    fn as_ref(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const T,4) }
+    }
+}
+
+impl<T> AsMut<[T]> for Vec4<T> {
+    fn as_mut(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut T,4) }
+    }
+}
+
+// vector == vector
+impl<T: PartialEq> PartialEq<Vec4<T>> for Vec4<T> {
+    fn eq(&self,other: &Vec4<T>) -> bool {
+        (self.x == other.x) &&
+        (self.y == other.y) &&
+        (self.z == other.z) &&
+        (self.w == other.w)
+    }
+}
+
+// scalar * vector
+macro_rules! scalar_vec4 {
+    ($($t:ty)*) => ($(
+        impl Mul<Vec4<$t>> for $t {
+            type Output = Vec4<$t>;
+            fn mul(self,other: Vec4<$t>) -> Vec4<$t> {
+                Vec4 {
+                    x: self * other.x,
+                    y: self * other.y,
+                    z: self * other.z,
+                    w: self * other.w,
+                }
+            }
+        }
+    )*)
+}
+
+scalar_vec4! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+// rational * vector
+macro_rules! rational_vec4 {
+    ($(($t:ty,$ut:ty))*) => ($(
+        impl Mul<Vec4<Rational<$t,$ut>>> for Rational<$t,$ut> {
+            type Output = Vec4<Rational<$t,$ut>>;
+            fn mul(self,other: Vec4<Rational<$t,$ut>>) -> Vec4<Rational<$t,$ut>> {
+                Vec4 {
+                    x: self * other.x,
+                    y: self * other.y,
+                    z: self * other.z,
+                    w: self * other.w,
+                }
+            }
+        }
+    )*)
+}
+
+rational_vec4! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+
+// fixed * vector
+impl<T,const B: usize> Mul<Vec4<Fixed<T,B>>> for Fixed<T,B> where Fixed<T,B>: Copy + Mul<Output=Fixed<T,B>> {
+    type Output = Vec4<Fixed<T,B>>;
+    fn mul(self,other: Vec4<Fixed<T,B>>) -> Vec4<Fixed<T,B>> {
+        Vec4 {
+            x: self * other.x,
+            y: self * other.y,
+            z: self * other.z,
+            w: self * other.w,
+        }
+    }
+}
+
+// vector + vector
+impl<T: Add<Output=T>> Add<Vec4<T>> for Vec4<T> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self::Output {
+        Vec4 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+// vector += vector
+impl<T: AddAssign> AddAssign<Vec4<T>> for Vec4<T> {
+    fn add_assign(&mut self,other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+        self.w += other.w;
+    }
+}
+
+// vector - vector
+impl<T: Sub<Output=T>> Sub<Vec4<T>> for Vec4<T> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self::Output {
+        Vec4 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+// vector -= vector
+impl<T: SubAssign> SubAssign<Vec4<T>> for Vec4<T> {
+    fn sub_assign(&mut self,other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+        self.w -= other.w;
+    }
+}
+
+// vector * scalar
+impl<T: Copy + Mul<Output=T>> Mul<T> for Vec4<T> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self::Output {
+        Vec4 {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+            w: self.w * other,
+        }
+    }
+}
+
+// vector *= scalar
+impl<T: Copy + MulAssign> MulAssign<T> for Vec4<T> {
+    fn mul_assign(&mut self,other: T) {
+        self.x *= other;
+        self.y *= other;
+        self.z *= other;
+        self.w *= other;
+    }
+}
+
+// vector / scalar
+impl<T: Copy + Div<Output=T>> Div<T> for Vec4<T> {
+    type Output = Self;
+    fn div(self,other: T) -> Self::Output {
+        Vec4 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+// vector /= scalar
+impl<T: Copy + DivAssign> DivAssign<T> for Vec4<T> {
+    fn div_assign(&mut self,other: T) {
+        self.x /= other;
+        self.y /= other;
+        self.z /= other;
+        self.w /= other;
+    }
+}
+
+// -vector
+impl<T: Neg<Output=T>> Neg for Vec4<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Vec4 {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub type f32v4 = Vec4<f32>;
+#[allow(non_camel_case_types)]
+pub type f64v4 = Vec4<f64>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slices() {
+        let mut v = Vec4 { x: 1.5f64,y: -2.0,z: 0.25,w: 1.0, };
+        assert_eq!(v.as_ref(),[1.5,-2.0,0.25,1.0]);
+        v.as_mut()[3] = 4.0;
+        assert_eq!(v.w,4.0);
+        let mut v = Vec4 { x: 6u8,y: 7,z: 8,w: 9, };
+        v.as_mut().reverse();
+        assert_eq!(v,Vec4 { x: 9,y: 8,z: 7,w: 6, });
+    }
+
+    #[test]
+    fn homogeneous() {
+        assert_eq!(Vec4 { x: 2.0,y: -4.0,z: 6.0,w: 2.0, }.from_homogeneous(),Vec3 { x: 1.0,y: -2.0,z: 3.0, });
+        let p = Vec3 { x: 1.0,y: -2.0,z: 3.0, };
+        assert_eq!(p.to_homogeneous_point().from_homogeneous(),p);
+        assert_eq!(p.extend(5.0).truncate(),p);
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vec4 { x: 1,y: 2,z: 3,w: 4, };
+        assert_eq!(<[i32; 4]>::from(v),[1,2,3,4]);
+        assert_eq!(<(i32,i32,i32,i32)>::from(v),(1,2,3,4));
+        assert_eq!(Vec4::from([1,2,3,4]),v);
+        assert_eq!(Vec4::from(&[1,2,3,4]),v);
+        assert_eq!(Vec4::from((1,2,3,4)),v);
+        assert_eq!(v.wzyx(),Vec4 { x: 4,y: 3,z: 2,w: 1, });
+        assert_eq!(Vec4 { x: 0.0f64,y: 0.0,z: 0.0,w: 0.0, }.try_normalize(),None);
+    }
+}
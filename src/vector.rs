@@ -0,0 +1,423 @@
+use {
+    crate::*,
+    std::{
+        cmp::PartialEq,
+        fmt::{
+            Display,
+            Formatter,
+            Result,
+        },
+        ops::{
+            Index,
+            IndexMut,
+        },
+    },
+};
+
+// swizzle methods returning Vec2, Vec3 and Vec4, used inside the vector impls
+macro_rules! swizzle_vec2 {
+    ($($name:ident: $a:ident $b:ident),*) => ($(
+        pub fn $name(self) -> Vec2<T> {
+            Vec2 { x: self.$a,y: self.$b, }
+        }
+    )*)
+}
+
+macro_rules! swizzle_vec3 {
+    ($($name:ident: $a:ident $b:ident $c:ident),*) => ($(
+        pub fn $name(self) -> Vec3<T> {
+            Vec3 { x: self.$a,y: self.$b,z: self.$c, }
+        }
+    )*)
+}
+
+macro_rules! swizzle_vec4 {
+    ($($name:ident: $a:ident $b:ident $c:ident $d:ident),*) => ($(
+        pub fn $name(self) -> Vec4<T> {
+            Vec4 { x: self.$a,y: self.$b,z: self.$c,w: self.$d, }
+        }
+    )*)
+}
+
+/// Rounding mode for converting real vectors into integer vectors.
+#[derive(Copy,Clone,Debug,PartialEq)]
+pub enum Rounding {
+    /// round towards negative infinity.
+    Floor,
+    /// round towards positive infinity.
+    Ceil,
+    /// round to the nearest integer, halfway cases away from 0.
+    Round,
+    /// round towards 0.
+    Trunc,
+}
+
+impl Rounding {
+
+    /// round `value` according to this mode.
+    pub fn apply<T: Real>(self,value: T) -> T {
+        match self {
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+            Rounding::Round => value.round(),
+            Rounding::Trunc => value.trunc(),
+        }
+    }
+}
+
+// the grid coordinates one step below, at and one step above `value`, None where the step would overflow
+pub(crate) fn grid_steps<T: Copy + One + PartialEq + Unsigned + Add<Output=T> + Sub<Output=T>>(value: T) -> [Option<T>; 3] {
+    [
+        if value == T::MIN { None } else { Some(value - T::ONE) },
+        Some(value),
+        if value == T::MAX { None } else { Some(value + T::ONE) },
+    ]
+}
+
+/// Vector trait.
+///
+/// Vectors of any dimension ([`Vec2`], [`Vec3`], [`Vec4`], [`VecN`], and the grade-1 parts of the multivectors) share the same
+/// basic geometry, so code that only needs dot products, lengths and interpolation can be written once over the `Vector`
+/// trait.
+pub trait Vector<T>: Copy + Add<Output=Self> + Sub<Output=Self> + Mul<T,Output=Self> + Div<T,Output=Self> {
+
+    /// Number of components.
+    const COMPONENTS: usize;
+
+    /// Dot product.
+    fn dot(self,other: Self) -> T;
+
+    /// Squared length, avoids the square root.
+    fn length_squared(self) -> T {
+        self.dot(self)
+    }
+
+    /// Length.
+    fn length(self) -> T where T: Real {
+        self.length_squared().sqrt()
+    }
+
+    /// Vector with the same direction and length 1.
+    fn normalize(self) -> Self where T: Real {
+        self / self.length()
+    }
+
+    /// Vector with the same direction and length 1, or `None` if the vector has length 0.
+    fn try_normalize(self) -> Option<Self> where T: Real + Zero + PartialEq {
+        let length = self.length();
+        if length == T::ZERO {
+            None
+        }
+        else {
+            Some(self / length)
+        }
+    }
+
+    /// Distance between two points.
+    fn distance(self,other: Self) -> T where T: Real {
+        (other - self).length()
+    }
+
+    /// Linear interpolation, `t` = 0 returns `self`, `t` = 1 returns `other`.
+    fn lerp(self,other: Self,t: T) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<T: Copy + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vector<T> for Vec2<T> {
+    const COMPONENTS: usize = 2;
+    fn dot(self,other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl<T: Copy + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vector<T> for Vec3<T> {
+    const COMPONENTS: usize = 3;
+    fn dot(self,other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T: Copy + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>> Vector<T> for Vec4<T> {
+    const COMPONENTS: usize = 4;
+    fn dot(self,other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+}
+
+/// N-dimensional vector template.
+///
+/// For dimensions that have no dedicated type (like [`Vec2`], [`Vec3`] or [`Vec4`]), e.g. state vectors in signal processing
+/// or feature vectors. The components are stored in an array, and can be indexed directly.
+///
+/// Can use any scalar underneath (typically [`f32`] or [`f64`]), as well as integers, [`Rational`] and [`Fixed`] types.
+#[derive(Copy,Clone,Debug)]
+pub struct VecN<T,const N: usize>(pub [T; N]);
+
+impl<T: Zero,const N: usize> VecN<T,N> {
+
+    /// vector with all components 0.
+    pub const ZERO: Self = VecN([T::ZERO; N]);
+}
+
+impl<T: Copy,const N: usize> VecN<T,N> {
+
+    /// apply `f` to each component.
+    pub fn map<U,F: FnMut(T) -> U>(self,f: F) -> VecN<U,N> {
+        VecN(self.0.map(f))
+    }
+
+    /// apply `f` to each pair of components of `self` and `other`.
+    pub fn zip_map<U: Copy,V,F: FnMut(T,U) -> V>(self,other: VecN<U,N>,mut f: F) -> VecN<V,N> {
+        VecN(std::array::from_fn(|i| f(self.0[i],other.0[i])))
+    }
+}
+
+/// Display the vector as `(a,b,...)`.
+impl<T: Display,const N: usize> Display for VecN<T,N> {
+    fn fmt(&self,f: &mut Formatter) -> Result {
+        write!(f,"(")?;
+        for (i,c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f,",")?;
+            }
+            write!(f,"{}",c)?;
+        }
+        write!(f,")")
+    }
+}
+
+impl<T,const N: usize> Index<usize> for VecN<T,N> {
+    type Output = T;
+    fn index(&self,index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T,const N: usize> IndexMut<usize> for VecN<T,N> {
+    fn index_mut(&mut self,index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T,const N: usize> From<[T; N]> for VecN<T,N> {
+    fn from(array: [T; N]) -> Self {
+        VecN(array)
+    }
+}
+
+impl<T,const N: usize> From<VecN<T,N>> for [T; N] {
+    fn from(vector: VecN<T,N>) -> Self {
+        vector.0
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T,2> {
+    fn from(vector: Vec2<T>) -> Self {
+        VecN([vector.x,vector.y])
+    }
+}
+
+impl<T> From<VecN<T,2>> for Vec2<T> {
+    fn from(vector: VecN<T,2>) -> Self {
+        let [x,y] = vector.0;
+        Vec2 { x,y, }
+    }
+}
+
+impl<T> From<Vec3<T>> for VecN<T,3> {
+    fn from(vector: Vec3<T>) -> Self {
+        VecN([vector.x,vector.y,vector.z])
+    }
+}
+
+impl<T> From<VecN<T,3>> for Vec3<T> {
+    fn from(vector: VecN<T,3>) -> Self {
+        let [x,y,z] = vector.0;
+        Vec3 { x,y,z, }
+    }
+}
+
+impl<T> From<Vec4<T>> for VecN<T,4> {
+    fn from(vector: Vec4<T>) -> Self {
+        VecN([vector.x,vector.y,vector.z,vector.w])
+    }
+}
+
+impl<T> From<VecN<T,4>> for Vec4<T> {
+    fn from(vector: VecN<T,4>) -> Self {
+        let [x,y,z,w] = vector.0;
+        Vec4 { x,y,z,w, }
+    }
+}
+
+impl<T: Copy + Zero + Add<Output=T> + Sub<Output=T> + Mul<Output=T> + Div<Output=T>,const N: usize> Vector<T> for VecN<T,N> {
+    const COMPONENTS: usize = N;
+    fn dot(self,other: Self) -> T {
+        self.0.iter().zip(other.0.iter()).fold(T::ZERO,|sum,(a,b)| sum + *a * *b)
+    }
+}
+
+// vector == vector
+impl<T: PartialEq,const N: usize> PartialEq<VecN<T,N>> for VecN<T,N> {
+    fn eq(&self,other: &VecN<T,N>) -> bool {
+        self.0 == other.0
+    }
+}
+
+// scalar * vector
+macro_rules! scalar_vecn {
+    ($($t:ty)*) => ($(
+        impl<const N: usize> Mul<VecN<$t,N>> for $t {
+            type Output = VecN<$t,N>;
+            fn mul(self,other: VecN<$t,N>) -> VecN<$t,N> {
+                VecN(other.0.map(|c| self * c))
+            }
+        }
+    )*)
+}
+
+scalar_vecn! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+// rational * vector
+macro_rules! rational_vecn {
+    ($(($t:ty,$ut:ty))*) => ($(
+        impl<const N: usize> Mul<VecN<Rational<$t,$ut>,N>> for Rational<$t,$ut> {
+            type Output = VecN<Rational<$t,$ut>,N>;
+            fn mul(self,other: VecN<Rational<$t,$ut>,N>) -> VecN<Rational<$t,$ut>,N> {
+                VecN(other.0.map(|c| self * c))
+            }
+        }
+    )*)
+}
+
+rational_vecn! { (usize,usize) (u8,u8) (u16,u16) (u32,u32) (u64,u64) (u128,u128) (isize,usize) (i8,u8) (i16,u16) (i32,u32) (i64,u64) (i128,u128) }
+
+// fixed * vector
+impl<T,const B: usize,const N: usize> Mul<VecN<Fixed<T,B>,N>> for Fixed<T,B> where Fixed<T,B>: Copy + Mul<Output=Fixed<T,B>> {
+    type Output = VecN<Fixed<T,B>,N>;
+    fn mul(self,other: VecN<Fixed<T,B>,N>) -> VecN<Fixed<T,B>,N> {
+        VecN(other.0.map(|c| self * c))
+    }
+}
+
+// vector + vector
+impl<T: Copy + Add<Output=T>,const N: usize> Add<VecN<T,N>> for VecN<T,N> {
+    type Output = Self;
+    fn add(self,other: Self) -> Self::Output {
+        VecN(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+// vector += vector
+impl<T: AddAssign,const N: usize> AddAssign<VecN<T,N>> for VecN<T,N> {
+    fn add_assign(&mut self,other: Self) {
+        for (c,o) in self.0.iter_mut().zip(other.0) {
+            *c += o;
+        }
+    }
+}
+
+// vector - vector
+impl<T: Copy + Sub<Output=T>,const N: usize> Sub<VecN<T,N>> for VecN<T,N> {
+    type Output = Self;
+    fn sub(self,other: Self) -> Self::Output {
+        VecN(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+// vector -= vector
+impl<T: SubAssign,const N: usize> SubAssign<VecN<T,N>> for VecN<T,N> {
+    fn sub_assign(&mut self,other: Self) {
+        for (c,o) in self.0.iter_mut().zip(other.0) {
+            *c -= o;
+        }
+    }
+}
+
+// vector * scalar
+impl<T: Copy + Mul<Output=T>,const N: usize> Mul<T> for VecN<T,N> {
+    type Output = Self;
+    fn mul(self,other: T) -> Self::Output {
+        VecN(self.0.map(|c| c * other))
+    }
+}
+
+// vector *= scalar
+impl<T: Copy + MulAssign,const N: usize> MulAssign<T> for VecN<T,N> {
+    fn mul_assign(&mut self,other: T) {
+        for c in self.0.iter_mut() {
+            *c *= other;
+        }
+    }
+}
+
+// vector / scalar
+impl<T: Copy + Div<Output=T>,const N: usize> Div<T> for VecN<T,N> {
+    type Output = Self;
+    fn div(self,other: T) -> Self::Output {
+        VecN(self.0.map(|c| c / other))
+    }
+}
+
+// vector /= scalar
+impl<T: Copy + DivAssign,const N: usize> DivAssign<T> for VecN<T,N> {
+    fn div_assign(&mut self,other: T) {
+        for c in self.0.iter_mut() {
+            *c /= other;
+        }
+    }
+}
+
+// -vector
+impl<T: Neg<Output=T>,const N: usize> Neg for VecN<T,N> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        VecN(self.0.map(|c| -c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let v = VecN([1,2,3]);
+        assert_eq!(v[1],2);
+        assert_eq!(<[i32; 3]>::from(v),[1,2,3]);
+        assert!(VecN::from([1,2,3]) == v);
+        assert!(VecN::from(Vec2 { x: 1,y: 2, }) == VecN([1,2]));
+        assert_eq!(Vec2::from(VecN([1,2])),Vec2 { x: 1,y: 2, });
+        assert!(VecN::from(Vec3 { x: 1,y: 2,z: 3, }) == v);
+        assert_eq!(Vec3::from(v),Vec3 { x: 1,y: 2,z: 3, });
+        assert!(VecN::from(Vec4 { x: 1,y: 2,z: 3,w: 4, }) == VecN([1,2,3,4]));
+        assert_eq!(Vec4::from(VecN([1,2,3,4])),Vec4 { x: 1,y: 2,z: 3,w: 4, });
+        let mut v = v;
+        v[2] = 5;
+        assert_eq!(v.0,[1,2,5]);
+    }
+
+    #[test]
+    fn vector_trait() {
+        let a = VecN([2.0f64,3.0,0.0,-6.0,0.0]);
+        let b = VecN([0.0f64,1.0,1.0,1.0,1.0]);
+        assert_eq!(a.dot(b),-3.0);
+        assert_eq!(a.length(),7.0);
+        assert_eq!(a.distance(a * 2.0),7.0);
+        assert!(a.lerp(b,0.5) == (a + b) / 2.0);
+        assert_eq!(VecN::<f64,5>::ZERO.try_normalize().map(|v| v.0),None);
+        assert!((a.normalize().length() - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn rounding() {
+        let cases = [(-1.5,-2.0,-1.0,-2.0,-1.0),(2.5,2.0,3.0,3.0,2.0),(0.25,0.0,1.0,0.0,0.0)];
+        for (value,floor,ceil,round,trunc) in cases {
+            assert_eq!(Rounding::Floor.apply(value),floor);
+            assert_eq!(Rounding::Ceil.apply(value),ceil);
+            assert_eq!(Rounding::Round.apply(value),round);
+            assert_eq!(Rounding::Trunc.apply(value),trunc);
+        }
+    }
+}